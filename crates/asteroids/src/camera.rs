@@ -1,23 +1,48 @@
 use bevy::prelude::*;
+use bevy::render::camera::Viewport;
 use bevy::window::PrimaryWindow;
 
 #[derive(Component)]
 pub struct MainCamera;
 
+/// Column of the window rendered by the observer's camera, window is split into `count` equal
+/// columns.
+#[derive(Debug, Clone, Copy, Component)]
+pub struct ObserverViewport {
+    pub index: u32,
+    pub count: u32,
+}
+
 #[derive(Resource)]
 pub struct CameraSettings {
     pub scale: f32,
 }
 
 pub fn sys_update_camera(
-    mut camera_query: Query<&mut OrthographicProjection, With<MainCamera>>,
+    mut camera_query: Query<
+        (&mut OrthographicProjection, &mut Camera, &ObserverViewport),
+        With<MainCamera>,
+    >,
     window_query: Query<&Window, With<PrimaryWindow>>,
     settings: Res<CameraSettings>,
 ) {
     let window = window_query.single();
     let height = window.height();
 
-    let mut camera = camera_query.single_mut();
+    for (mut projection, mut camera, viewport) in camera_query.iter_mut() {
+        projection.scale = settings.scale / height;
+
+        if viewport.count <= 1 {
+            camera.viewport = None;
+            continue;
+        }
+
+        let width = window.physical_width() / viewport.count;
 
-    camera.scale = settings.scale / height;
+        camera.viewport = Some(Viewport {
+            physical_position: UVec2::new(width * viewport.index, 0),
+            physical_size: UVec2::new(width, window.physical_height()),
+            ..default()
+        });
+    }
 }
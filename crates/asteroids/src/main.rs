@@ -1,22 +1,74 @@
 use asteroids::*;
 use bevy::prelude::*;
+use bevy_relativity::{sys_apply_scenario, RelativityPlugin, Scenario, SimulationArgs};
+use clap::error::ErrorKind;
+use clap::{CommandFactory, Parser};
+
+/// Relativistic asteroids: fly through a 2D field of objects and see them as light shows them.
+#[derive(Debug, Parser)]
+struct Cli {
+    #[command(flatten)]
+    simulation: SimulationArgs,
+
+    /// Width of the window in logical pixels
+    #[arg(long, default_value_t = 1280.0)]
+    width: f32,
+
+    /// Height of the window in logical pixels
+    #[arg(long, default_value_t = 720.0)]
+    height: f32,
+
+    /// Height of the visible area in world units
+    #[arg(long, default_value_t = 20.0)]
+    scale: f32,
+}
 
 fn main() {
-    App::new()
-        .add_plugins((DefaultPlugins.set(ImagePlugin::default_nearest()),))
-        .insert_resource(CameraSettings { scale: 20.0 })
-        .insert_resource(RelativeSettings {
-            speed_of_light: 1.0,
-        })
-        .add_systems(Startup, (sys_setup,))
-        .add_systems(
-            Update,
-            (
-                sys_control_observer,
-                sys_update_camera,
-                sys_update_observer,
-                sys_update_relative_objects,
-            ),
-        )
-        .run();
+    let cli = Cli::parse();
+
+    let scenario = cli
+        .simulation
+        .load_scenario(DEFAULT_SCENARIO)
+        .unwrap_or_else(|error| Cli::command().error(ErrorKind::InvalidValue, error).exit());
+
+    let window = Window {
+        resolution: (cli.width, cli.height).into(),
+        ..default()
+    };
+
+    let mut app = App::new();
+
+    app.add_plugins((
+        DefaultPlugins
+            .set(WindowPlugin {
+                primary_window: Some(window),
+                ..default()
+            })
+            .set(ImagePlugin::default_nearest()),
+        RelativityPlugin {
+            speed_of_light: scenario.speed_of_light,
+        },
+    ))
+    .insert_resource(CameraSettings { scale: cli.scale })
+    .add_systems(Startup, (sys_setup,))
+    .add_systems(
+        PreUpdate,
+        sys_spawn_scenario_objects
+            .after(sys_apply_scenario)
+            .run_if(resource_exists_and_changed::<Scenario>),
+    )
+    .add_systems(
+        Update,
+        (
+            sys_read_control_input,
+            sys_update_camera,
+            (sys_update_observer_label, sys_update_object_appearance),
+        ),
+    );
+
+    cli.simulation
+        .setup(&mut app, scenario)
+        .expect("failed to set up input files");
+
+    app.run();
 }
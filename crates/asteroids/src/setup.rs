@@ -1,132 +1,230 @@
 use crate::*;
-use bevy::math::DVec3;
 use bevy::prelude::*;
-use bevy::sprite::{Material2d, MaterialMesh2dBundle, Mesh2dHandle};
-use relativity::SpacetimeEvent;
-
-struct ObjectInit {
-    coord: SpacetimeEvent,
-    velocity: DVec3,
+use bevy::render::view::RenderLayers;
+use bevy::sprite::{MaterialMesh2dBundle, Mesh2dHandle};
+use bevy_relativity::{
+    ControlledObserver,
+    DopplerPalette,
+    ObjectImage,
+    ObserverData,
+    RelativeObject,
+    Scenario,
+    ScenarioObject,
+    ScenarioObserver,
+    VisibilityStats,
+};
+use relativity::WorldLine;
+
+/// Scenario used when no scenario file is given.
+pub const DEFAULT_SCENARIO: &str = include_str!("../scenarios/grid.ron");
+
+/// Everything needed to spawn relative objects whenever the scenario changes.
+#[derive(Debug, Resource)]
+pub struct ObjectSpawner {
+    mesh: Mesh2dHandle,
+    observers: Vec<Entity>,
 }
 
 const OBJECT_SIZE: f32 = 0.2;
 
+/// Color of the object at rest relative to the observer. It is neutral gray, so both the red
+/// and the blue Doppler tint stay visible.
+pub const OBJECT_COLOR: LinearRgba = LinearRgba::rgb(0.8, 0.8, 0.8);
+
 pub fn sys_setup(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<ColorMaterial>>,
+    scenario: Res<Scenario>,
 ) {
-    commands.spawn(Camera2dBundle::default()).insert(MainCamera);
-
     let circle = Mesh2dHandle(meshes.add(Circle {
         radius: OBJECT_SIZE * 0.5,
     }));
     let rect = Mesh2dHandle(meshes.add(Rectangle::new(OBJECT_SIZE, OBJECT_SIZE)));
 
-    let color = Color::srgb(1.0, 0.0, 0.0);
+    // every observer of the scenario renders its own column of the window, the number of
+    // observers doesn't change when the scenario is reloaded
+    let count = scenario.observers.len().max(1) as u32;
+
+    let observers = (0..count)
+        .map(|index| {
+            let observer_data = scenario
+                .observer(index as usize)
+                .observer_data(scenario.speed_of_light);
+            spawn_observer(
+                &mut commands,
+                index,
+                count,
+                observer_data,
+                rect.clone(),
+                &mut materials,
+            )
+        })
+        .collect::<Vec<_>>();
+
+    // every observer can see other observers
+    for (index, &observer) in observers.iter().enumerate() {
+        for &object in observers.iter().filter(|&&object| object != observer) {
+            commands
+                .spawn(MaterialMesh2dBundle {
+                    mesh: rect.clone(),
+                    material: materials.add(Color::srgb(0.0, 0.0, 1.0)),
+                    ..default()
+                })
+                .insert(ObjectImage { object, observer })
+                .insert(observer_layer(index as u32));
+        }
+    }
 
-    let next_id = 0;
+    commands.insert_resource(ObjectSpawner {
+        mesh: circle,
+        observers,
+    });
 
-    let _next_id = grid_objects(
-        &mut commands,
-        SpacetimeEvent::new(DVec3::X * 4.0).with_time(-1000.0),
-        DVec3::ZERO,
-        32,
-        2.0,
-        32,
-        2.0,
-        next_id,
-        circle.clone(),
-        materials.add(color),
-    );
+    commands.insert_resource(DopplerPalette::new(
+        OBJECT_COLOR,
+        &mut materials,
+        ColorMaterial::from_color,
+    ));
+}
 
-    let object_entity = commands
-        .spawn(MaterialMesh2dBundle {
-            mesh: rect,
-            material: materials.add(Color::srgb(0.0, 0.0, 1.0)),
+/// Spawn relative objects of the [`Scenario`] whenever it changes, objects of the previous one
+/// are despawned by [`bevy_relativity::sys_apply_scenario`].
+pub fn sys_spawn_scenario_objects(
+    mut commands: Commands,
+    scenario: Res<Scenario>,
+    spawner: Res<ObjectSpawner>,
+    palette: Res<DopplerPalette<ColorMaterial>>,
+) {
+    let world_lines = match scenario.world_lines() {
+        Ok(world_lines) => world_lines,
+        Err(error) => {
+            warn!("invalid scenario: {error}");
+            return;
+        }
+    };
+
+    let next_id = spawner.observers.len() as u32;
+
+    for (id, world_line) in (next_id..).zip(world_lines) {
+        spawn_object(
+            &mut commands,
+            id,
+            world_line,
+            spawner.mesh.clone(),
+            palette.unshifted(),
+            &spawner.observers,
+        );
+    }
+}
+
+/// Spawn observer with its own camera, the first observer is controlled by the user. Observer
+/// has an id of the relative object, so other observers can see it.
+fn spawn_observer(
+    commands: &mut Commands,
+    index: u32,
+    count: u32,
+    observer_data: ObserverData,
+    mesh: Mesh2dHandle,
+    materials: &mut Assets<ColorMaterial>,
+) -> Entity {
+    let layer = observer_layer(index);
+
+    let mut observer = commands.spawn(MaterialMesh2dBundle {
+        mesh,
+        material: materials.add(Color::srgb(0.0, 0.0, 1.0)),
+        ..default()
+    });
+
+    observer
+        .insert(Name::new(format!("observer_{}", index)))
+        .insert(RelativeObject::from_world_line(
+            index,
+            observer_data.world_line.clone(),
+        ))
+        .insert(observer_data)
+        .insert(ScenarioObserver(index as usize))
+        .insert(VisibilityStats::default())
+        .insert(layer.clone());
+
+    if index == 0 {
+        observer.insert(ControlledObserver);
+    }
+
+    let observer_entity = observer.id();
+
+    commands
+        .spawn(Camera2dBundle {
+            camera: Camera {
+                order: index as isize,
+                ..default()
+            },
             ..default()
         })
-        .insert(Name::new("observer"))
-        .insert(ObserverData {
-            proper_time: 0.0,
-            velocity: DVec3::ZERO,
-            coord: SpacetimeEvent::ZERO,
-        })
-        .id();
+        .insert(MainCamera)
+        .insert(ObserverViewport { index, count })
+        .insert(layer.clone())
+        .set_parent(observer_entity);
 
     commands
         .spawn(Text2dBundle {
             transform: Transform::from_translation(Vec3::Z * 0.7).with_scale(Vec3::splat(0.01)),
-            text: Text::from_section(format!("observer"), TextStyle::default()),
+            text: Text::from_section("observer", TextStyle::default()),
             visibility: Visibility::Inherited,
             ..Default::default()
         })
-        .set_parent(object_entity);
+        .insert(layer)
+        .set_parent(observer_entity);
+
+    observer_entity
 }
 
-/// Spawn objects in grid pattern
-fn grid_objects<M: Material2d>(
+/// Render layer of everything seen by the observer with the given index.
+fn observer_layer(index: u32) -> RenderLayers {
+    RenderLayers::layer(index as usize)
+}
+
+/// Spawn the object and its image for every observer.
+fn spawn_object(
     commands: &mut Commands,
-    coord: SpacetimeEvent,
-    velocity: DVec3,
-    rows: u32,
-    row_spacing: f64,
-    cols: u32,
-    col_spacing: f64,
-    id_start: u32,
+    id: u32,
+    world_line: WorldLine,
     mesh: Mesh2dHandle,
-    material: Handle<M>,
-) -> u32 {
-    let x_offset = cols as f64 * col_spacing * 0.5;
-    let y_offset = rows as f64 * row_spacing * 0.5;
-
-    for i in 0..rows {
-        for j in 0..cols {
-            let id = id_start + i * cols + j;
-
-            let mut object_coord = coord;
-            object_coord.pos += DVec3::new(
-                j as f64 * col_spacing - x_offset,
-                i as f64 * row_spacing - y_offset,
-                0.0,
-            );
-
-            ObjectInit {
-                coord: object_coord,
-                velocity,
-            }
-            .spawn(commands, id, mesh.clone(), material.clone());
-        }
-    }
+    material: &Handle<ColorMaterial>,
+    observers: &[Entity],
+) {
+    let object_entity = commands
+        .spawn(Name::new(format!("object_{}", id)))
+        .insert(RelativeObject::from_world_line(id, world_line))
+        .insert(ScenarioObject)
+        .id();
 
-    id_start + rows * cols
-}
+    for (index, &observer) in observers.iter().enumerate() {
+        let layer = observer_layer(index as u32);
 
-impl ObjectInit {
-    fn spawn<M: Material2d>(
-        &self,
-        commands: &mut Commands,
-        id: u32,
-        mesh: Mesh2dHandle,
-        material: Handle<M>,
-    ) {
-        let object_entity = commands
+        // images switch between materials of the palette, see `sys_update_object_appearance`
+        let image_entity = commands
             .spawn(MaterialMesh2dBundle {
-                mesh,
-                material,
+                mesh: mesh.clone(),
+                material: material.clone(),
                 ..default()
             })
-            .insert(Name::new(format!("object_{}", id)))
-            .insert(RelativeObject::new(id, self.coord, self.velocity))
+            .insert(ObjectImage {
+                object: object_entity,
+                observer,
+            })
+            .insert(ScenarioObject)
+            .insert(layer.clone())
             .id();
 
         commands
             .spawn(Text2dBundle {
                 transform: Transform::from_translation(Vec3::Z * 0.7).with_scale(Vec3::splat(0.01)),
-                text: Text::from_section(format!("object"), TextStyle::default()),
+                text: Text::from_section("object", TextStyle::default()),
                 visibility: Visibility::Inherited,
                 ..Default::default()
             })
-            .set_parent(object_entity);
+            .insert(layer)
+            .set_parent(image_entity);
     }
 }
@@ -0,0 +1,76 @@
+use asteroids::*;
+use bevy::prelude::*;
+use bevy_relativity::*;
+use std::time::Duration;
+
+/// Application with the systems of `main` which don't need a window.
+fn app(scenario: Scenario) -> App {
+    let mut app = App::new();
+    app.init_resource::<Time>()
+        .init_resource::<Assets<Mesh>>()
+        .init_resource::<Assets<ColorMaterial>>()
+        .add_plugins(RelativityPlugin {
+            speed_of_light: scenario.speed_of_light,
+        })
+        .insert_resource(scenario)
+        .add_systems(Startup, sys_setup)
+        .add_systems(
+            PreUpdate,
+            sys_spawn_scenario_objects
+                .after(sys_apply_scenario)
+                .run_if(resource_exists_and_changed::<Scenario>),
+        );
+
+    app.world_mut().run_schedule(Startup);
+    app.world_mut().run_schedule(PreUpdate);
+
+    app
+}
+
+fn observers(app: &mut App) -> Vec<(Entity, ScenarioObserver)> {
+    let mut observers = app
+        .world_mut()
+        .query_filtered::<(Entity, &ScenarioObserver), With<ObserverData>>()
+        .iter(app.world())
+        .map(|(entity, &index)| (entity, index))
+        .collect::<Vec<_>>();
+    observers.sort_by_key(|&(_, ScenarioObserver(index))| index);
+
+    observers
+}
+
+#[test]
+fn test_default_scenario_observers_see_each_other() {
+    let scenario = Scenario::from_ron(DEFAULT_SCENARIO).unwrap();
+    assert!(scenario.observers.len() >= 2);
+
+    let mut app = app(scenario.clone());
+
+    let observers = observers(&mut app);
+    assert_eq!(observers.len(), scenario.observers.len());
+
+    // every observer starts from its own initial state
+    for (index, &(entity, _)) in observers.iter().enumerate() {
+        let observer = app.world().get::<ObserverData>(entity).unwrap();
+        assert_eq!(observer.coord, scenario.observers[index].coord);
+    }
+
+    for _ in 0..5 * 64 {
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(Duration::from_secs_f64(1.0 / 64.0));
+        app.world_mut().run_schedule(FixedUpdate);
+    }
+
+    let world = app.world();
+
+    for &(observer, _) in &observers {
+        for &(other, _) in observers.iter().filter(|&&(other, _)| other != observer) {
+            let object = world.get::<RelativeObject>(other).unwrap();
+            assert!(
+                object.visible_event(observer).is_some(),
+                "{observer} doesn't see {other}"
+            );
+        }
+    }
+}
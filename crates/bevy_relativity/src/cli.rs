@@ -0,0 +1,117 @@
+use crate::*;
+use bevy::prelude::*;
+use std::io;
+use std::path::PathBuf;
+
+/// Command-line arguments of the simulation, flatten them into the application's arguments with
+/// `#[command(flatten)]`.
+#[derive(Debug, Clone, Default, clap::Args)]
+pub struct SimulationArgs {
+    /// Scenario file in RON format
+    #[arg(long, value_name = "PATH")]
+    pub scenario: Option<PathBuf>,
+
+    /// Speed of light [default: from the scenario]
+    #[arg(short = 'c', long)]
+    pub speed_of_light: Option<f64>,
+
+    /// Number of objects in clouds and along every axis of grids [default: from the scenario]
+    #[arg(short = 'n', long)]
+    pub count: Option<u32>,
+
+    /// Distance between neighbouring objects of grids [default: from the scenario]
+    #[arg(long)]
+    pub spacing: Option<f64>,
+
+    /// Proper acceleration of the observer's thrust [default: from the scenario]
+    #[arg(short = 'a', long)]
+    pub acceleration: Option<f64>,
+
+    /// Seed of random clouds [default: from the scenario]
+    #[arg(long)]
+    pub seed: Option<u64>,
+
+    /// Record the scenario and the observer's control input to the file
+    #[arg(long, value_name = "PATH")]
+    pub record: Option<PathBuf>,
+
+    /// Replay the scenario and the observer's control input recorded with --record
+    #[arg(
+        long,
+        value_name = "PATH",
+        conflicts_with_all = ["scenario", "speed_of_light", "count", "spacing", "acceleration", "seed"],
+    )]
+    pub replay: Option<PathBuf>,
+}
+
+impl SimulationArgs {
+    pub fn overrides(&self) -> ScenarioOverrides {
+        ScenarioOverrides {
+            speed_of_light: self.speed_of_light,
+            object_count: self.count,
+            spacing: self.spacing,
+            acceleration: self.acceleration,
+            seed: self.seed,
+        }
+    }
+
+    /// Load the scenario file, or the `default` scenario if there is no file, with the overrides
+    /// from the arguments.
+    pub fn load_scenario(&self, default: &str) -> Result<Scenario, ScenarioError> {
+        match &self.scenario {
+            Some(path) => self.overrides().load(path),
+            None => self.overrides().from_ron(default),
+        }
+    }
+
+    /// Insert `scenario` and set up [`ScenarioFile`], [`InputRecorder`] and [`InputReplay`] from
+    /// the arguments.
+    ///
+    /// A replay replaces `scenario` and the fixed timestep with the recorded ones. The recording
+    /// gets the fixed timestep of the app, so its [`Time<Fixed>`] should be set up before.
+    pub fn setup(&self, app: &mut App, mut scenario: Scenario) -> io::Result<()> {
+        if self.record.is_some() && self.record == self.replay {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "--record and --replay must be different files",
+            ));
+        }
+
+        // load the replay before the recording is created, so it can't be truncated
+        let replay = self.replay.as_ref().map(InputReplay::load).transpose()?;
+
+        if let Some(header) = replay.as_ref().and_then(InputReplay::header) {
+            scenario = header.scenario.clone();
+            app.insert_resource(Time::<Fixed>::from_duration(header.timestep));
+        }
+
+        if let Some(path) = &self.record {
+            let timestep = app
+                .world()
+                .get_resource::<Time<Fixed>>()
+                .cloned()
+                .unwrap_or_default()
+                .timestep();
+
+            app.insert_resource(InputRecorder::create(
+                path,
+                &RecordingHeader {
+                    scenario: scenario.clone(),
+                    timestep,
+                },
+            )?);
+        }
+
+        if let Some(replay) = replay {
+            app.insert_resource(replay);
+        }
+
+        if let Some(path) = &self.scenario {
+            app.insert_resource(ScenarioFile::new(path).with_overrides(self.overrides()));
+        }
+
+        app.insert_resource(scenario);
+
+        Ok(())
+    }
+}
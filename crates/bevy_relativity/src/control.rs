@@ -0,0 +1,211 @@
+use crate::*;
+use bevy::math::DVec3;
+use bevy::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, BufWriter, Write};
+use std::path::Path;
+use std::time::Duration;
+
+/// Observer controlled by the user through [`ControlInput`].
+#[derive(Debug, Clone, Copy, Component)]
+pub struct ControlledObserver;
+
+/// Input of the controlled observer applied in the current fixed timestep.
+///
+/// The simulation depends only on the sequence of these inputs, so a trip can be recorded with
+/// [`InputRecorder`] and reproduced with [`InputReplay`] as long as the fixed timestep is the same.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Resource, Serialize, Deserialize)]
+pub struct ControlInput {
+    /// Direction of the thrust in the observer's rest frame, zero if there is no thrust.
+    pub thrust: DVec3,
+    /// Destroy objects around the observer, see [`sys_destroy_objects`].
+    pub action: bool,
+}
+
+/// Input collected from the user since the last fixed timestep, it becomes the [`ControlInput`]
+/// of the next one.
+///
+/// The action stays triggered until the next fixed timestep, so short presses between timesteps
+/// are not lost.
+#[derive(Debug, Default, Resource)]
+pub struct PendingControlInput(pub ControlInput);
+
+/// Everything besides the inputs needed to reproduce a recording, the first line of the file
+/// written by [`InputRecorder`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RecordingHeader {
+    /// Scenario the recording starts from.
+    pub scenario: Scenario,
+    /// Fixed timestep of every recorded input.
+    pub timestep: Duration,
+}
+
+/// Writes [`RecordingHeader`] and then [`ControlInput`] of every fixed timestep to a file as JSON
+/// lines.
+///
+/// The recording is only valid for the scenario of its header, so the recorder should be removed
+/// when the scenario changes, see [`sys_reload_scenario`].
+#[derive(Debug, Resource)]
+pub struct InputRecorder {
+    writer: BufWriter<File>,
+}
+
+impl InputRecorder {
+    pub fn create(path: impl AsRef<Path>, header: &RecordingHeader) -> io::Result<Self> {
+        let mut writer = BufWriter::new(File::create(path)?);
+        serde_json::to_writer(&mut writer, header)?;
+        writeln!(writer)?;
+        writer.flush()?;
+
+        Ok(Self { writer })
+    }
+
+    pub fn record(&mut self, input: &ControlInput) -> io::Result<()> {
+        serde_json::to_writer(&mut self.writer, input)?;
+        writeln!(self.writer)?;
+
+        // keep the file complete even if the app is killed
+        self.writer.flush()
+    }
+}
+
+/// Feeds recorded [`ControlInput`]s instead of the user input, one per fixed timestep. When the
+/// recording is over, the observer stops thrusting.
+#[derive(Debug, Default, Resource)]
+pub struct InputReplay {
+    header: Option<RecordingHeader>,
+    inputs: VecDeque<ControlInput>,
+}
+
+impl InputReplay {
+    /// Replay of the inputs without a header, for the scenario and timestep chosen by the caller.
+    pub fn new(inputs: impl IntoIterator<Item = ControlInput>) -> Self {
+        Self {
+            header: None,
+            inputs: inputs.into_iter().collect(),
+        }
+    }
+
+    /// Load the header and inputs written by [`InputRecorder`].
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let mut header = None;
+        let mut inputs = VecDeque::new();
+
+        for line in BufReader::new(File::open(path)?).lines() {
+            let line = line?;
+
+            if line.trim().is_empty() {
+                continue;
+            }
+
+            if header.is_none() {
+                header = Some(serde_json::from_str(&line)?);
+            } else {
+                inputs.push_back(serde_json::from_str(&line)?);
+            }
+        }
+
+        if header.is_none() {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "recording has no header",
+            ));
+        }
+
+        Ok(Self { header, inputs })
+    }
+
+    /// Scenario and timestep of the recording, `None` if it was not loaded from a file.
+    pub fn header(&self) -> Option<&RecordingHeader> {
+        self.header.as_ref()
+    }
+
+    /// Number of fixed timesteps left in the recording.
+    pub fn remaining(&self) -> usize {
+        self.inputs.len()
+    }
+
+    pub fn next_input(&mut self) -> Option<ControlInput> {
+        self.inputs.pop_front()
+    }
+}
+
+/// Take [`ControlInput`] of the current fixed timestep from [`InputReplay`] if it exists or from
+/// [`PendingControlInput`] otherwise, and write it to [`InputRecorder`] if it exists.
+pub fn sys_next_control_input(
+    mut input: ResMut<ControlInput>,
+    mut pending: ResMut<PendingControlInput>,
+    replay: Option<ResMut<InputReplay>>,
+    recorder: Option<ResMut<InputRecorder>>,
+) {
+    *input = match replay {
+        Some(mut replay) => replay.next_input().unwrap_or_default(),
+        None => pending.0,
+    };
+    pending.0.action = false;
+
+    if let Some(mut recorder) = recorder {
+        if let Err(error) = recorder.record(&input) {
+            warn!("failed to record control input: {error}");
+        }
+    }
+}
+
+/// Apply the thrust of [`ControlInput`] to every [`ControlledObserver`]. The motion itself is
+/// integrated by [`sys_update_observer`].
+pub fn sys_control_observer(
+    mut observer_query: Query<&mut ObserverData, With<ControlledObserver>>,
+    input: Res<ControlInput>,
+    settings: Res<RelativeSettings>,
+) {
+    let c = settings.speed_of_light;
+
+    for mut observer in observer_query.iter_mut() {
+        // thrust is applied in the observer's rest frame
+        let proper_acceleration = input.thrust * observer.acceleration;
+        observer.set_proper_acceleration(proper_acceleration, c);
+    }
+}
+
+/// Radius around the observer (in its reference frame) in which objects are destroyed.
+pub const DESTRUCTION_RADIUS: f64 = 3.0;
+
+/// Destroy objects around every [`ControlledObserver`] on the [`ControlInput`] action. Observers
+/// see the destruction only when the light from it reaches them.
+pub fn sys_destroy_objects(
+    input: Res<ControlInput>,
+    observer_query: Query<&ObserverData, With<ControlledObserver>>,
+    mut objects: Query<&mut RelativeObject, Without<ObserverData>>,
+    settings: Res<RelativeSettings>,
+) {
+    if !input.action {
+        return;
+    }
+
+    let c = settings.speed_of_light;
+
+    for observer in observer_query.iter() {
+        let frame = observer.reference_frame(c);
+
+        for mut object in objects.iter_mut() {
+            // already destroyed around another observer
+            if object.world_line.end_event().is_some() {
+                continue;
+            }
+
+            let Ok(current_event) = object.world_line.get_current_event(frame) else {
+                continue;
+            };
+
+            if current_event.relative_position.length() > DESTRUCTION_RADIUS {
+                continue;
+            }
+
+            if let Err(error) = object.world_line.end_at(current_event.coord.time, c) {
+                warn!("failed to destroy object {}: {error}", object.id);
+            }
+        }
+    }
+}
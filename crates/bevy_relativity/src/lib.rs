@@ -0,0 +1,17 @@
+mod cli;
+mod control;
+#[cfg(feature = "render")]
+mod object_image;
+mod observer;
+mod plugin;
+mod relative_object;
+mod scenario;
+
+pub use cli::*;
+pub use control::*;
+#[cfg(feature = "render")]
+pub use object_image::*;
+pub use observer::*;
+pub use plugin::*;
+pub use relative_object::*;
+pub use scenario::*;
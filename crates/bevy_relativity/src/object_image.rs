@@ -0,0 +1,132 @@
+use crate::*;
+use bevy::prelude::*;
+use relativity::VisibleWorldLineEvent;
+
+/// Entity displaying the [`RelativeObject`] as it is seen by the [`ObserverData`] entity.
+///
+/// Image may be the object entity itself. To show objects to several observers (e.g. split
+/// screen), spawn an image per observer and render it only to the observer's camera, e.g. with
+/// [`RenderLayers`](bevy::render::view::RenderLayers).
+#[derive(Debug, Clone, Copy, Component)]
+pub struct ObjectImage {
+    pub object: Entity,
+    pub observer: Entity,
+}
+
+/// Place every object image at the visible position of its object relative to its observer.
+pub fn sys_update_object_images(
+    mut images: Query<(&ObjectImage, &mut Transform, &mut Visibility)>,
+    objects: Query<&RelativeObject>,
+) {
+    for (image, mut transform, mut visible) in images.iter_mut() {
+        let Some(visible_event) = objects
+            .get(image.object)
+            .ok()
+            .and_then(|object| object.visible_event(image.observer))
+        else {
+            *visible = Visibility::Hidden;
+            continue;
+        };
+
+        *visible = Visibility::Visible;
+
+        let relative_coord = visible_event.relative_coord;
+
+        transform.translation.x = relative_coord.pos.x as f32;
+        transform.translation.y = relative_coord.pos.y as f32;
+        transform.translation.z = relative_coord.pos.z as f32;
+    }
+}
+
+/// Maximal brightness multiplier of the relativistic beaming, so strongly blueshifted objects
+/// don't blow out the picture.
+const MAX_BRIGHTNESS: f64 = 16.0;
+
+/// Tint `color` red or blue according to the Doppler factor of the visible event and scale its
+/// brightness by the relativistic beaming.
+pub fn doppler_shifted_color(
+    color: LinearRgba,
+    visible_event: &VisibleWorldLineEvent,
+    c: f64,
+) -> LinearRgba {
+    doppler_factor_color(color, visible_event.doppler_factor(c))
+}
+
+/// Same as [`doppler_shifted_color`] for the given Doppler factor, the beaming intensity factor is
+/// `doppler_factor^4`.
+pub fn doppler_factor_color(color: LinearRgba, doppler_factor: f64) -> LinearRgba {
+    let shift = doppler_factor.log2().clamp(-1.0, 1.0) as f32;
+    let brightness = doppler_factor.powi(4).min(MAX_BRIGHTNESS) as f32;
+
+    let intensity = color.red.max(color.green).max(color.blue);
+    let tint = if shift < 0.0 {
+        LinearRgba::rgb(intensity, 0.0, 0.0)
+    } else {
+        LinearRgba::rgb(0.0, 0.0, intensity)
+    };
+
+    let shifted = color.mix(&tint, shift.abs());
+
+    LinearRgba::new(
+        shifted.red * brightness,
+        shifted.green * brightness,
+        shifted.blue * brightness,
+        color.alpha,
+    )
+}
+
+/// Range of the Doppler factor octaves (`log2` of it) covered by [`DopplerPalette`]. Below it
+/// images are almost black, above it [`MAX_BRIGHTNESS`] is reached.
+const PALETTE_MIN_OCTAVE: f64 = -4.0;
+const PALETTE_MAX_OCTAVE: f64 = 1.0;
+
+/// Number of materials of [`DopplerPalette`] per octave of the Doppler factor.
+const PALETTE_STEPS_PER_OCTAVE: f64 = 32.0;
+
+/// Materials of the object color shifted by quantized Doppler factors, shared by all object
+/// images.
+///
+/// Images switch between the shared materials instead of changing their own ones every frame, so
+/// the number of materials doesn't grow with the number of objects and only images whose Doppler
+/// factor crosses a step are touched.
+#[derive(Debug, Resource)]
+pub struct DopplerPalette<M: Asset> {
+    materials: Vec<Handle<M>>,
+}
+
+impl<M: Asset> DopplerPalette<M> {
+    /// Add a material for every quantized Doppler factor, `material` creates it from `color`
+    /// shifted by [`doppler_factor_color`].
+    pub fn new(
+        color: LinearRgba,
+        assets: &mut Assets<M>,
+        material: impl Fn(LinearRgba) -> M,
+    ) -> Self {
+        let steps = ((PALETTE_MAX_OCTAVE - PALETTE_MIN_OCTAVE) * PALETTE_STEPS_PER_OCTAVE) as usize;
+
+        let materials = (0..=steps)
+            .map(|step| {
+                let octave = PALETTE_MIN_OCTAVE + step as f64 / PALETTE_STEPS_PER_OCTAVE;
+
+                assets.add(material(doppler_factor_color(color, octave.exp2())))
+            })
+            .collect();
+
+        Self { materials }
+    }
+
+    /// Material of the color seen with the Doppler factor `doppler_factor`, factors outside of
+    /// the palette get its first or last material.
+    pub fn material(&self, doppler_factor: f64) -> &Handle<M> {
+        let step = (doppler_factor.log2() - PALETTE_MIN_OCTAVE) * PALETTE_STEPS_PER_OCTAVE;
+        // saturating cast, NaN becomes 0
+        let index = (step.round() as usize).min(self.materials.len() - 1);
+
+        &self.materials[index]
+    }
+
+    /// Material of the color at rest relative to the observer.
+    pub fn unshifted(&self) -> &Handle<M> {
+        self.material(1.0)
+    }
+}
@@ -0,0 +1,164 @@
+use crate::*;
+use bevy::math::DVec3;
+use bevy::prelude::*;
+use relativity::{
+    coordinate_acceleration,
+    MotionState,
+    Rapidity,
+    ReferenceFrame,
+    SpacetimeEvent,
+    WorldLine,
+    WorldLineEvent,
+};
+
+/// observer data
+///
+/// Any number of observers can exist at the same time, every one of them sees all
+/// [`RelativeObject`]s through [`ObjectImage`]s.
+#[derive(Debug, Component)]
+pub struct ObserverData {
+    pub proper_time: f64,
+    /// Rapidity of the observer in main reference frame, see [`Rapidity`].
+    pub rapidity: Rapidity,
+    /// Current position in main reference frame.
+    pub coord: SpacetimeEvent,
+    /// Magnitude of the proper acceleration produced by the controls.
+    pub acceleration: f64,
+    /// Current proper acceleration of the observer, i.e. its acceleration in the observer's rest
+    /// frame at the start of the current segment. Use [`ObserverData::set_proper_acceleration`]
+    /// to change it.
+    pub proper_acceleration: DVec3,
+    /// State of the observer when the current segment of constant proper acceleration started,
+    /// see [`ObserverData::record_event`].
+    pub segment_start: MotionState,
+    /// History of the observer's motion in main reference frame, see
+    /// [`ObserverData::record_event`].
+    pub world_line: WorldLine,
+}
+
+impl ObserverData {
+    pub fn new(coord: SpacetimeEvent, acceleration: f64) -> Self {
+        Self {
+            proper_time: 0.0,
+            rapidity: Rapidity::ZERO,
+            coord,
+            acceleration,
+            proper_acceleration: DVec3::ZERO,
+            segment_start: MotionState::new(coord),
+            world_line: WorldLine::new(WorldLineEvent {
+                coord,
+                rapidity: Rapidity::ZERO,
+                object_proper_time: 0.0,
+                proper_acceleration: DVec3::ZERO,
+            }),
+        }
+    }
+
+    /// Observer with the given initial velocity in main reference frame.
+    pub fn with_velocity(mut self, velocity: DVec3, c: f64) -> Self {
+        self.rapidity = Rapidity::from_velocity(velocity, c);
+        self.record_event(c);
+
+        self
+    }
+
+    /// Current state of the observer.
+    pub fn motion_state(&self) -> MotionState {
+        MotionState {
+            coord: self.coord,
+            rapidity: self.rapidity,
+            proper_time: self.proper_time,
+        }
+    }
+
+    /// Change proper acceleration of the observer starting from its current state.
+    pub fn set_proper_acceleration(&mut self, proper_acceleration: DVec3, c: f64) {
+        if proper_acceleration == self.proper_acceleration {
+            return;
+        }
+
+        self.proper_acceleration = proper_acceleration;
+        self.record_event(c);
+    }
+
+    /// Start a new segment of the observer's motion from its current state and append it to the
+    /// world line, must be called whenever the velocity or the proper acceleration of the observer
+    /// changes.
+    pub fn record_event(&mut self, c: f64) {
+        self.segment_start = self.motion_state();
+
+        let event = self
+            .segment_start
+            .to_world_line_event(self.proper_acceleration);
+
+        let result = if self.coord.time > self.world_line.last_event().coord.time {
+            self.world_line.push(event, c)
+        } else {
+            // motion changed again at the same instant (e.g. frame with zero duration)
+            self.world_line
+                .set_last_rapidity(event.rapidity, c)
+                .map(|_| ())
+                .and_then(|()| {
+                    self.world_line
+                        .set_last_proper_acceleration(event.proper_acceleration)
+                })
+        };
+
+        if let Err(error) = result {
+            warn!("failed to record observer world line: {error}");
+        }
+    }
+
+    /// Move the observer forward by `delta_proper_time` of its proper time.
+    ///
+    /// The state is computed from the start of the current segment, so it doesn't depend on how
+    /// the proper time is split into steps and always lies on the observer's world line.
+    pub fn advance(&mut self, delta_proper_time: f64, c: f64) {
+        let segment_proper_time =
+            self.proper_time + delta_proper_time - self.segment_start.proper_time;
+        let state = self
+            .segment_start
+            .advance(self.proper_acceleration, segment_proper_time, c);
+
+        self.coord = state.coord;
+        self.rapidity = state.rapidity;
+        self.proper_time = state.proper_time;
+    }
+
+    /// Velocity of the observer in main reference frame.
+    pub fn velocity(&self, c: f64) -> DVec3 {
+        self.rapidity.to_velocity(c)
+    }
+
+    /// Current acceleration of the observer in main reference frame.
+    pub fn coordinate_acceleration(&self, c: f64) -> DVec3 {
+        // the motion is hyperbolic in the rest frame of the segment start
+        let start_frame = ReferenceFrame::new(self.segment_start.coord, c)
+            .with_rapidity(self.segment_start.rapidity);
+        let velocity = start_frame.velocity_to_frame(self.velocity(c));
+
+        start_frame.acceleration_from_frame(
+            velocity,
+            coordinate_acceleration(velocity, self.proper_acceleration, c),
+        )
+    }
+
+    /// Rest frame of the observer with the origin at its current position.
+    pub fn reference_frame(&self, c: f64) -> ReferenceFrame {
+        ReferenceFrame::new(self.coord, c).with_rapidity(self.rapidity)
+    }
+}
+
+pub fn sys_update_observer(
+    mut observer_query: Query<&mut ObserverData>,
+    time: Res<Time>,
+    settings: Res<RelativeSettings>,
+) {
+    let proper_time_delta = time.delta_seconds_f64();
+
+    let c = settings.speed_of_light;
+
+    for mut observer in observer_query.iter_mut() {
+        observer.advance(proper_time_delta, c);
+    }
+}
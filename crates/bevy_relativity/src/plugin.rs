@@ -0,0 +1,88 @@
+use crate::*;
+use bevy::prelude::*;
+
+/// System sets of the [`RelativityPlugin`], executed in the declaration order in [`FixedUpdate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, SystemSet)]
+pub enum RelativitySet {
+    /// Take [`ControlInput`] of the current timestep from the user or from the replay.
+    Input,
+    /// Apply the [`ControlInput`]: destroy objects on the action and change observer's motion.
+    Control,
+    /// Advance observers in time.
+    UpdateObserver,
+    /// Compute visible events of relative objects for every observer and place their images
+    /// (with the `render` feature).
+    UpdateObjects,
+}
+
+/// Relativistic visibility of [`RelativeObject`]s for every [`ObserverData`] entity.
+///
+/// The simulation runs in [`FixedUpdate`], so it doesn't depend on the frame rate and can be
+/// reproduced from the recorded [`ControlInput`]s. Systems which read user input should write
+/// [`PendingControlInput`] in [`Update`], systems which render visible objects (colors, labels,
+/// etc.) should run in [`Update`] too.
+///
+/// If the [`Scenario`] resource exists, it is applied in [`PreUpdate`] whenever it changes (and
+/// reloaded from [`ScenarioFile`] if it exists), systems which spawn objects of the scenario
+/// should run after [`sys_apply_scenario`].
+#[derive(Debug, Clone, Copy)]
+pub struct RelativityPlugin {
+    pub speed_of_light: f64,
+}
+
+impl Default for RelativityPlugin {
+    fn default() -> Self {
+        Self {
+            speed_of_light: 1.0,
+        }
+    }
+}
+
+impl Plugin for RelativityPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(RelativeSettings {
+            speed_of_light: self.speed_of_light,
+        })
+        .init_resource::<ControlInput>()
+        .init_resource::<PendingControlInput>()
+        .add_systems(
+            PreUpdate,
+            (
+                sys_reload_scenario.run_if(resource_exists::<ScenarioFile>),
+                sys_apply_scenario.run_if(resource_exists_and_changed::<Scenario>),
+            )
+                .chain(),
+        )
+        .configure_sets(
+            FixedUpdate,
+            (
+                RelativitySet::Input,
+                RelativitySet::Control,
+                RelativitySet::UpdateObserver,
+                RelativitySet::UpdateObjects,
+            )
+                .chain(),
+        )
+        .add_systems(
+            FixedUpdate,
+            (
+                sys_next_control_input.in_set(RelativitySet::Input),
+                (sys_destroy_objects, sys_control_observer)
+                    .chain()
+                    .in_set(RelativitySet::Control),
+                sys_update_observer.in_set(RelativitySet::UpdateObserver),
+                (sys_sync_observer_world_lines, sys_update_relative_objects)
+                    .chain()
+                    .in_set(RelativitySet::UpdateObjects),
+            ),
+        );
+
+        #[cfg(feature = "render")]
+        app.add_systems(
+            FixedUpdate,
+            sys_update_object_images
+                .after(sys_update_relative_objects)
+                .in_set(RelativitySet::UpdateObjects),
+        );
+    }
+}
@@ -0,0 +1,180 @@
+use crate::*;
+use bevy::ecs::entity::EntityHashMap;
+use bevy::math::DVec3;
+use bevy::prelude::*;
+use relativity::{
+    Rapidity,
+    SpacetimeEvent,
+    VisibleWorldLineEvent,
+    WorldLine,
+    WorldLineError,
+    WorldLineEvent,
+    WorldLineQueryError,
+};
+
+#[derive(Debug, Component)]
+pub struct RelativeObject {
+    pub id: u32,
+    pub world_line: WorldLine,
+    /// Events of the world line visible to every observer entity which can see the object.
+    pub visible_events: EntityHashMap<VisibleWorldLineEvent>,
+}
+
+impl RelativeObject {
+    pub fn new(id: u32, coord: SpacetimeEvent, rapidity: Rapidity) -> Self {
+        Self::from_world_line(
+            id,
+            WorldLine::new(WorldLineEvent {
+                coord,
+                rapidity,
+                object_proper_time: 0.0,
+                proper_acceleration: DVec3::ZERO,
+            }),
+        )
+    }
+
+    pub fn from_world_line(id: u32, world_line: WorldLine) -> Self {
+        Self {
+            id,
+            world_line,
+            visible_events: EntityHashMap::default(),
+        }
+    }
+
+    /// Event of the world line visible to the `observer` entity, `None` if the object is not
+    /// visible to it.
+    pub fn visible_event(&self, observer: Entity) -> Option<&VisibleWorldLineEvent> {
+        self.visible_events.get(&observer)
+    }
+}
+
+#[derive(Debug, Resource)]
+pub struct RelativeSettings {
+    pub speed_of_light: f64,
+}
+
+/// Results of the visibility queries of all relative objects in the last frame, updated for
+/// observer entities which have this component.
+#[derive(Debug, Default, Component)]
+pub struct VisibilityStats {
+    pub visible: u32,
+    pub not_yet_visible: u32,
+    pub already_ended: u32,
+    pub degenerate_velocity: u32,
+    pub numerical_failures: u32,
+}
+
+impl VisibilityStats {
+    /// Record failed visibility query.
+    pub fn add_error(&mut self, error: WorldLineQueryError) {
+        match error {
+            WorldLineQueryError::NotYetVisible => self.not_yet_visible += 1,
+            WorldLineQueryError::AlreadyEnded { .. } => self.already_ended += 1,
+            WorldLineQueryError::DegenerateVelocity { .. } => self.degenerate_velocity += 1,
+            WorldLineQueryError::NumericalFailure { .. } => self.numerical_failures += 1,
+        }
+    }
+
+    /// Number of queries failed for reasons other than the object not being visible yet or
+    /// having already ended.
+    pub fn failures(&self) -> u32 {
+        self.degenerate_velocity + self.numerical_failures
+    }
+}
+
+/// Append new events of observers' world lines to their [`RelativeObject`]s, so observers with
+/// this component can be seen by other observers.
+pub fn sys_sync_observer_world_lines(
+    mut query: Query<(&ObserverData, &mut RelativeObject)>,
+    settings: Res<RelativeSettings>,
+) {
+    let c = settings.speed_of_light;
+
+    for (observer, mut object) in query.iter_mut() {
+        if let Err(error) = sync_world_line(&mut object.world_line, &observer.world_line, c) {
+            warn!("failed to sync observer world line: {error}");
+        }
+    }
+}
+
+fn sync_world_line(
+    target: &mut WorldLine,
+    source: &WorldLine,
+    c: f64,
+) -> Result<(), WorldLineError> {
+    let synced = target.events().len();
+
+    // motion of the last synced event may be changed by `WorldLine::set_last_rapidity` and
+    // `WorldLine::set_last_proper_acceleration`
+    if let Some(event) = source.events().get(synced - 1) {
+        target.set_last_rapidity(event.rapidity, c)?;
+        target.set_last_proper_acceleration(event.proper_acceleration)?;
+    }
+
+    for event in source.events().iter().skip(synced) {
+        target.push(*event, c)?;
+    }
+
+    Ok(())
+}
+
+/// Find events of every relative object visible to every observer (except the object itself).
+pub fn sys_update_relative_objects(
+    mut objects: Query<(Entity, &mut RelativeObject)>,
+    mut observers: Query<(Entity, &ObserverData, Option<&mut VisibilityStats>)>,
+    settings: Res<RelativeSettings>,
+) {
+    let c = settings.speed_of_light;
+
+    for (_, _, stats) in observers.iter_mut() {
+        if let Some(mut stats) = stats {
+            *stats = VisibilityStats::default();
+        }
+    }
+
+    // failed queries are logged once per frame, they usually fail for many objects at once
+    let mut failures = 0;
+    let mut first_failure = None;
+
+    for (object_entity, mut object) in objects.iter_mut() {
+        let object = &mut *object;
+        object.visible_events.clear();
+
+        for (observer_entity, observer, mut stats) in observers.iter_mut() {
+            if observer_entity == object_entity {
+                continue;
+            }
+
+            let visible_event = object
+                .world_line
+                .get_visible_event(observer.reference_frame(c));
+
+            match visible_event {
+                Ok(visible_event) => {
+                    if let Some(stats) = stats.as_mut() {
+                        stats.visible += 1;
+                    }
+                    object.visible_events.insert(observer_entity, visible_event);
+                }
+                Err(error) => {
+                    if let Some(stats) = stats.as_mut() {
+                        stats.add_error(error);
+                    }
+
+                    if matches!(
+                        error,
+                        WorldLineQueryError::DegenerateVelocity { .. }
+                            | WorldLineQueryError::NumericalFailure { .. }
+                    ) {
+                        failures += 1;
+                        first_failure.get_or_insert(error);
+                    }
+                }
+            }
+        }
+    }
+
+    if let Some(error) = first_failure {
+        warn!("{failures} visibility queries failed, the first one with: {error}");
+    }
+}
@@ -0,0 +1,592 @@
+use crate::*;
+use bevy::math::{DVec3, UVec3};
+use bevy::prelude::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use relativity::{
+    velocity_to_new_rf,
+    Rapidity,
+    SpacetimeEvent,
+    WorldLine,
+    WorldLineError,
+    WorldLineEvent,
+};
+use ron::extensions::Extensions;
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, SystemTime};
+
+/// Initial state of the simulation: speed of light, observers and relative objects.
+///
+/// Scenarios are written in RON, e.g.
+///
+/// ```ron
+/// (
+///     speed_of_light: 1.0,
+///     observers: [
+///         (acceleration: 1.0),
+///         (coord: (pos: (0.0, 2.0, 0.0), time: 0.0)),
+///     ],
+///     objects: [
+///         Grid(
+///             center: (pos: (4.0, 0.0, 0.0), time: -1000.0),
+///             count: (32, 32, 1),
+///             spacing: (2.0, 2.0, 0.0),
+///         ),
+///         Body(
+///             coord: (pos: (0.0, 10.0, 0.0), time: 0.0),
+///             velocity: (0.5, 0.0, 0.0),
+///             maneuvers: [(proper_time: 5.0, proper_acceleration: (0.0, -0.1, 0.0))],
+///         ),
+///     ],
+/// )
+/// ```
+#[derive(Debug, Clone, PartialEq, Resource, Serialize, Deserialize)]
+pub struct Scenario {
+    pub speed_of_light: f64,
+    /// Initial states of observers, the first one is controlled by the user. Applications spawn
+    /// their observers from this list at startup, see [`ScenarioObserver`].
+    #[serde(default = "default_observers")]
+    pub observers: Vec<ObserverInit>,
+    #[serde(default)]
+    pub objects: Vec<ObjectGenerator>,
+}
+
+fn default_observers() -> Vec<ObserverInit> {
+    vec![ObserverInit::default()]
+}
+
+/// Initial state of an observer.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ObserverInit {
+    /// Initial position in main reference frame.
+    pub coord: SpacetimeEvent,
+    /// Initial velocity in main reference frame.
+    pub velocity: DVec3,
+    /// Magnitude of the proper acceleration produced by the controls, see
+    /// [`ObserverData::acceleration`].
+    pub acceleration: f64,
+}
+
+impl Default for ObserverInit {
+    fn default() -> Self {
+        Self {
+            coord: SpacetimeEvent::ZERO,
+            velocity: DVec3::ZERO,
+            acceleration: 1.0,
+        }
+    }
+}
+
+impl ObserverInit {
+    pub fn observer_data(&self, c: f64) -> ObserverData {
+        ObserverData::new(self.coord, self.acceleration).with_velocity(self.velocity, c)
+    }
+}
+
+/// Change of the object's proper acceleration.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Maneuver {
+    /// Proper time of the object since its first event when the maneuver starts.
+    pub proper_time: f64,
+    /// Proper acceleration of the object until the next maneuver, see
+    /// [`WorldLineEvent::proper_acceleration`].
+    pub proper_acceleration: DVec3,
+}
+
+/// Single relative object.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Body {
+    /// First event of the object's world line, the object doesn't exist before it.
+    pub coord: SpacetimeEvent,
+    /// Initial velocity in main reference frame.
+    #[serde(default)]
+    pub velocity: DVec3,
+    #[serde(default)]
+    pub maneuvers: Vec<Maneuver>,
+}
+
+impl Body {
+    /// World line of the object following its maneuvers.
+    pub fn world_line(&self, c: f64) -> Result<WorldLine, ScenarioError> {
+        let mut maneuvers = self.maneuvers.clone();
+        maneuvers.sort_by(|a, b| a.proper_time.total_cmp(&b.proper_time));
+
+        let mut events = vec![WorldLineEvent {
+            coord: self.coord,
+            rapidity: Rapidity::from_velocity(self.velocity, c),
+            object_proper_time: 0.0,
+            proper_acceleration: DVec3::ZERO,
+        }];
+
+        for maneuver in maneuvers {
+            if !(maneuver.proper_time.is_finite() && maneuver.proper_time >= 0.0) {
+                return Err(ScenarioError::InvalidManeuver {
+                    proper_time: maneuver.proper_time,
+                });
+            }
+
+            let last = events.last_mut().expect("world line has the first event");
+
+            if maneuver.proper_time == last.object_proper_time {
+                last.proper_acceleration = maneuver.proper_acceleration;
+                continue;
+            }
+
+            let event = WorldLineEvent {
+                proper_acceleration: maneuver.proper_acceleration,
+                ..last.advance(maneuver.proper_time - last.object_proper_time, c)
+            };
+            events.push(event);
+        }
+
+        Ok(WorldLine::from_events(events, c)?)
+    }
+}
+
+/// Description of one or several relative objects.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum ObjectGenerator {
+    Body(Body),
+    /// Objects in the nodes of the grid centered at `center`, every one of them starts at the
+    /// time of `center`.
+    Grid {
+        center: SpacetimeEvent,
+        #[serde(default)]
+        velocity: DVec3,
+        /// Number of objects along every axis.
+        count: UVec3,
+        /// Distance between neighbouring objects along every axis.
+        spacing: DVec3,
+        #[serde(default)]
+        maneuvers: Vec<Maneuver>,
+    },
+    /// Objects placed randomly inside the ellipsoid with semi-axes `extent` centered at `center`,
+    /// every one of them starts at the time of `center`.
+    Cloud {
+        center: SpacetimeEvent,
+        /// Velocity of the cloud's rest frame in main reference frame.
+        #[serde(default)]
+        velocity: DVec3,
+        count: u32,
+        extent: DVec3,
+        /// Semi-axes of the ellipsoid of random velocities in the cloud's rest frame.
+        #[serde(default)]
+        velocity_spread: DVec3,
+        /// Seed of the random generator, the same seed always produces the same cloud.
+        #[serde(default)]
+        seed: u64,
+        #[serde(default)]
+        maneuvers: Vec<Maneuver>,
+    },
+}
+
+impl ObjectGenerator {
+    pub fn bodies(&self, c: f64) -> Vec<Body> {
+        match self {
+            Self::Body(body) => vec![body.clone()],
+            Self::Grid {
+                center,
+                velocity,
+                count,
+                spacing,
+                maneuvers,
+            } => {
+                let offset = (count.as_dvec3() - 1.0).max(DVec3::ZERO) * *spacing * 0.5;
+
+                (0..count.z)
+                    .flat_map(|k| {
+                        (0..count.y).flat_map(move |j| (0..count.x).map(move |i| (i, j, k)))
+                    })
+                    .map(|(i, j, k)| Body {
+                        coord: SpacetimeEvent {
+                            pos: center.pos + UVec3::new(i, j, k).as_dvec3() * *spacing - offset,
+                            time: center.time,
+                        },
+                        velocity: *velocity,
+                        maneuvers: maneuvers.clone(),
+                    })
+                    .collect()
+            }
+            Self::Cloud {
+                center,
+                velocity,
+                count,
+                extent,
+                velocity_spread,
+                seed,
+                maneuvers,
+            } => {
+                let mut rng = StdRng::seed_from_u64(*seed);
+
+                (0..*count)
+                    .map(|_| {
+                        let pos = center.pos + random_in_unit_ball(&mut rng) * *extent;
+                        let rest_velocity = random_in_unit_ball(&mut rng) * *velocity_spread;
+
+                        Body {
+                            coord: SpacetimeEvent {
+                                pos,
+                                time: center.time,
+                            },
+                            velocity: velocity_to_new_rf(-*velocity, rest_velocity, c),
+                            maneuvers: maneuvers.clone(),
+                        }
+                    })
+                    .collect()
+            }
+        }
+    }
+}
+
+fn random_in_unit_ball(rng: &mut impl Rng) -> DVec3 {
+    loop {
+        let point = DVec3::new(
+            rng.gen_range(-1.0..=1.0),
+            rng.gen_range(-1.0..=1.0),
+            rng.gen_range(-1.0..=1.0),
+        );
+
+        if point.length_squared() <= 1.0 {
+            return point;
+        }
+    }
+}
+
+impl Scenario {
+    /// Parse and validate the scenario.
+    pub fn from_ron(source: &str) -> Result<Self, ScenarioError> {
+        ScenarioOverrides::default().from_ron(source)
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ScenarioError> {
+        ScenarioOverrides::default().load(path)
+    }
+
+    fn parse(source: &str) -> Result<Self, ScenarioError> {
+        // allow `Body(coord: ...)` instead of `Body((coord: ...))`
+        Ok(ron::Options::default()
+            .with_default_extension(Extensions::UNWRAP_VARIANT_NEWTYPES)
+            .from_str(source)?)
+    }
+
+    /// Initial state of the observer with the given index, observers missing in the scenario
+    /// start from the default state.
+    pub fn observer(&self, index: usize) -> ObserverInit {
+        self.observers.get(index).copied().unwrap_or_default()
+    }
+
+    /// Check that the speed of light is valid and every object and observer can move as
+    /// described.
+    pub fn validate(&self) -> Result<(), ScenarioError> {
+        self.world_lines().map(|_| ())
+    }
+
+    /// All objects of the scenario in the order of generators.
+    pub fn bodies(&self) -> Vec<Body> {
+        let c = self.speed_of_light;
+
+        self.objects
+            .iter()
+            .flat_map(|generator| generator.bodies(c))
+            .collect()
+    }
+
+    /// World lines of all objects of the scenario, in the same order as [`Scenario::bodies`].
+    pub fn world_lines(&self) -> Result<Vec<WorldLine>, ScenarioError> {
+        let c = self.speed_of_light;
+
+        if !(c.is_finite() && c > 0.0) {
+            return Err(ScenarioError::InvalidSpeedOfLight { c });
+        }
+
+        // observers' world lines are checked the same way as objects' ones
+        for observer in &self.observers {
+            WorldLine::try_new(
+                WorldLineEvent {
+                    coord: observer.coord,
+                    rapidity: Rapidity::from_velocity(observer.velocity, c),
+                    object_proper_time: 0.0,
+                    proper_acceleration: DVec3::ZERO,
+                },
+                c,
+            )?;
+        }
+
+        self.bodies()
+            .iter()
+            .map(|body| body.world_line(c))
+            .collect()
+    }
+}
+
+/// Changes applied to every loaded [`Scenario`], e.g. from the command line, so they survive
+/// reloading of the [`ScenarioFile`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ScenarioOverrides {
+    pub speed_of_light: Option<f64>,
+    /// Number of objects in clouds and along every axis of grids which has more than one object.
+    pub object_count: Option<u32>,
+    /// Distance between neighbouring objects of grids along every axis with non-zero spacing.
+    pub spacing: Option<f64>,
+    /// Magnitude of every observer's proper acceleration produced by the controls.
+    pub acceleration: Option<f64>,
+    /// Seed of the first cloud, the following clouds get the next seeds.
+    pub seed: Option<u64>,
+}
+
+impl ScenarioOverrides {
+    pub fn apply(&self, scenario: &mut Scenario) {
+        if let Some(c) = self.speed_of_light {
+            scenario.speed_of_light = c;
+        }
+
+        if let Some(acceleration) = self.acceleration {
+            for observer in scenario.observers.iter_mut() {
+                observer.acceleration = acceleration;
+            }
+        }
+
+        let mut cloud_seeds = self.seed.map(|seed| seed..);
+
+        for generator in scenario.objects.iter_mut() {
+            match generator {
+                ObjectGenerator::Body(_) => {}
+                ObjectGenerator::Grid { count, spacing, .. } => {
+                    if let Some(object_count) = self.object_count {
+                        *count = UVec3::select(
+                            count.cmpgt(UVec3::ONE),
+                            UVec3::splat(object_count),
+                            *count,
+                        );
+                    }
+
+                    if let Some(new_spacing) = self.spacing {
+                        *spacing = DVec3::select(
+                            spacing.cmpne(DVec3::ZERO),
+                            DVec3::splat(new_spacing),
+                            *spacing,
+                        );
+                    }
+                }
+                ObjectGenerator::Cloud { count, seed, .. } => {
+                    if let Some(object_count) = self.object_count {
+                        *count = object_count;
+                    }
+
+                    if let Some(next_seed) = cloud_seeds.as_mut().and_then(Iterator::next) {
+                        *seed = next_seed;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Parse the scenario, apply the overrides and validate the result.
+    pub fn from_ron(&self, source: &str) -> Result<Scenario, ScenarioError> {
+        let mut scenario = Scenario::parse(source)?;
+        self.apply(&mut scenario);
+        scenario.validate()?;
+
+        Ok(scenario)
+    }
+
+    pub fn load(&self, path: impl AsRef<Path>) -> Result<Scenario, ScenarioError> {
+        self.from_ron(&std::fs::read_to_string(path)?)
+    }
+}
+
+/// Failure to load [`Scenario`].
+#[derive(Debug)]
+pub enum ScenarioError {
+    Io(io::Error),
+    Parse(ron::error::SpannedError),
+    /// Speed of light is not a positive finite number.
+    InvalidSpeedOfLight {
+        c: f64,
+    },
+    /// Maneuver starts before the object's first event.
+    InvalidManeuver {
+        proper_time: f64,
+    },
+    /// Object or observer can't move as described.
+    WorldLine(WorldLineError),
+}
+
+impl std::fmt::Display for ScenarioError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Io(error) => write!(f, "failed to read scenario: {error}"),
+            Self::Parse(error) => write!(f, "failed to parse scenario: {error}"),
+            Self::InvalidSpeedOfLight { c } => {
+                write!(f, "speed of light {c} is not a positive finite number")
+            }
+            Self::InvalidManeuver { proper_time } => write!(
+                f,
+                "maneuver at proper time {proper_time} does not happen after the object's start"
+            ),
+            Self::WorldLine(error) => write!(f, "invalid world line: {error}"),
+        }
+    }
+}
+
+impl std::error::Error for ScenarioError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Io(error) => Some(error),
+            Self::Parse(error) => Some(error),
+            Self::WorldLine(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for ScenarioError {
+    fn from(error: io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+impl From<ron::error::SpannedError> for ScenarioError {
+    fn from(error: ron::error::SpannedError) -> Self {
+        Self::Parse(error)
+    }
+}
+
+impl From<WorldLineError> for ScenarioError {
+    fn from(error: WorldLineError) -> Self {
+        Self::WorldLine(error)
+    }
+}
+
+/// Entity spawned from the [`Scenario`], e.g. a relative object or its image. It is despawned
+/// when the scenario is reloaded.
+#[derive(Debug, Clone, Copy, Component)]
+pub struct ScenarioObject;
+
+/// Index of the observer's initial state in [`Scenario::observers`], the observer is reset to it
+/// whenever the scenario changes. Observers without this component are reset to the first one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Component)]
+pub struct ScenarioObserver(pub usize);
+
+/// File of the current [`Scenario`], it is reloaded whenever the file changes.
+#[derive(Debug, Resource)]
+pub struct ScenarioFile {
+    path: PathBuf,
+    modified: Option<SystemTime>,
+    overrides: ScenarioOverrides,
+    timer: Timer,
+}
+
+/// How often [`ScenarioFile`] is checked for changes.
+const SCENARIO_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+impl ScenarioFile {
+    /// Watch the file which is already loaded.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        let path = path.into();
+        let modified = modified_time(&path);
+
+        Self {
+            path,
+            modified,
+            overrides: ScenarioOverrides::default(),
+            timer: Timer::new(SCENARIO_POLL_INTERVAL, TimerMode::Repeating),
+        }
+    }
+
+    /// Apply `overrides` to every reloaded scenario.
+    pub fn with_overrides(mut self, overrides: ScenarioOverrides) -> Self {
+        self.overrides = overrides;
+
+        self
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Load the scenario again if the file was modified since the last load.
+    pub fn reload_if_modified(&mut self) -> Option<Result<Scenario, ScenarioError>> {
+        let modified = modified_time(&self.path);
+
+        if modified == self.modified {
+            return None;
+        }
+
+        self.modified = modified;
+
+        Some(self.overrides.load(&self.path))
+    }
+}
+
+fn modified_time(path: &Path) -> Option<SystemTime> {
+    std::fs::metadata(path)
+        .and_then(|metadata| metadata.modified())
+        .ok()
+}
+
+/// Replace [`Scenario`] when its [`ScenarioFile`] changes. Invalid scenarios are reported and
+/// ignored, so the simulation keeps running until the file is fixed.
+///
+/// Recording stops on reload, because the [`InputRecorder`] header holds the previous scenario.
+pub fn sys_reload_scenario(
+    mut commands: Commands,
+    mut file: ResMut<ScenarioFile>,
+    recorder: Option<Res<InputRecorder>>,
+    time: Res<Time>,
+) {
+    if !file.timer.tick(time.delta()).just_finished() {
+        return;
+    }
+
+    match file.reload_if_modified() {
+        Some(Ok(scenario)) => {
+            info!("reloaded scenario {}", file.path.display());
+            commands.insert_resource(scenario);
+
+            if recorder.is_some() {
+                warn!("stopped recording control input, the scenario has changed");
+                commands.remove_resource::<InputRecorder>();
+            }
+        }
+        Some(Err(error)) => warn!("failed to reload scenario {}: {error}", file.path.display()),
+        None => {}
+    }
+}
+
+/// Restart the simulation from the changed [`Scenario`]: set the speed of light, reset every
+/// observer to its initial state (see [`ScenarioObserver`]) and despawn every [`ScenarioObject`].
+///
+/// Applications spawn relative objects of the new scenario in their own systems running after
+/// this one.
+pub fn sys_apply_scenario(
+    mut commands: Commands,
+    scenario: Res<Scenario>,
+    mut settings: ResMut<RelativeSettings>,
+    mut observers: Query<(
+        &mut ObserverData,
+        Option<&ScenarioObserver>,
+        Option<&mut RelativeObject>,
+    )>,
+    scenario_objects: Query<Entity, With<ScenarioObject>>,
+) {
+    let c = scenario.speed_of_light;
+    settings.speed_of_light = c;
+
+    for (mut observer, index, object) in observers.iter_mut() {
+        let ScenarioObserver(index) = index.copied().unwrap_or(ScenarioObserver(0));
+        *observer = scenario.observer(index).observer_data(c);
+
+        if let Some(mut object) = object {
+            object.world_line = observer.world_line.clone();
+            object.visible_events.clear();
+        }
+    }
+
+    for entity in scenario_objects.iter() {
+        commands.entity(entity).despawn_recursive();
+    }
+}
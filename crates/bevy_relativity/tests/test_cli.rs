@@ -0,0 +1,235 @@
+use bevy::math::{DVec3, UVec3};
+use bevy::prelude::*;
+use bevy_relativity::*;
+use clap::Parser;
+use std::path::PathBuf;
+
+#[derive(Debug, Parser)]
+struct Cli {
+    #[command(flatten)]
+    simulation: SimulationArgs,
+}
+
+const SCENARIO: &str = r#"
+(
+    speed_of_light: 1.0,
+    objects: [
+        Grid(
+            center: (pos: (0.0, 0.0, 0.0), time: 0.0),
+            count: (4, 4, 1),
+            spacing: (2.0, 2.0, 0.0),
+        ),
+        Cloud(
+            center: (pos: (0.0, 0.0, 0.0), time: 0.0),
+            count: 10,
+            extent: (1.0, 1.0, 1.0),
+        ),
+        Cloud(
+            center: (pos: (0.0, 0.0, 0.0), time: 0.0),
+            count: 10,
+            extent: (1.0, 1.0, 1.0),
+        ),
+    ],
+)
+"#;
+
+#[test]
+fn test_parse_simulation_args() {
+    let cli = Cli::try_parse_from([
+        "demo",
+        "-c",
+        "2.5",
+        "--count",
+        "8",
+        "--spacing",
+        "3",
+        "-a",
+        "0.5",
+        "--seed",
+        "7",
+        "--scenario",
+        "scenario.ron",
+        "--record",
+        "trip.jsonl",
+    ])
+    .unwrap();
+
+    let args = cli.simulation;
+    assert_eq!(args.scenario, Some(PathBuf::from("scenario.ron")));
+    assert_eq!(args.record, Some(PathBuf::from("trip.jsonl")));
+    assert_eq!(args.replay, None);
+    assert_eq!(
+        args.overrides(),
+        ScenarioOverrides {
+            speed_of_light: Some(2.5),
+            object_count: Some(8),
+            spacing: Some(3.0),
+            acceleration: Some(0.5),
+            seed: Some(7),
+        }
+    );
+
+    // nothing is overridden by default
+    let cli = Cli::try_parse_from(["demo"]).unwrap();
+    assert_eq!(cli.simulation.overrides(), ScenarioOverrides::default());
+    assert_eq!(
+        cli.simulation.load_scenario(SCENARIO).unwrap(),
+        Scenario::from_ron(SCENARIO).unwrap()
+    );
+
+    assert!(Cli::try_parse_from(["demo", "--count", "-1"]).is_err());
+}
+
+#[test]
+fn test_overrides_change_generators() {
+    let cli = Cli::try_parse_from([
+        "demo",
+        "-c",
+        "3",
+        "-n",
+        "8",
+        "--spacing",
+        "5",
+        "-a",
+        "2",
+        "--seed",
+        "7",
+    ])
+    .unwrap();
+    let scenario = cli.simulation.load_scenario(SCENARIO).unwrap();
+
+    assert_eq!(scenario.speed_of_light, 3.0);
+    assert_eq!(scenario.observers[0].acceleration, 2.0);
+
+    // flat axis of the grid stays flat
+    let ObjectGenerator::Grid { count, spacing, .. } = &scenario.objects[0] else {
+        panic!("grid expected");
+    };
+    assert_eq!(*count, UVec3::new(8, 8, 1));
+    assert_eq!(*spacing, DVec3::new(5.0, 5.0, 0.0));
+
+    let seeds = scenario.objects[1..]
+        .iter()
+        .map(|generator| match generator {
+            ObjectGenerator::Cloud { count, seed, .. } => {
+                assert_eq!(*count, 8);
+                *seed
+            }
+            _ => panic!("cloud expected"),
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(seeds, [7, 8]);
+
+    assert_eq!(scenario.bodies().len(), 8 * 8 + 8 + 8);
+}
+
+#[test]
+fn test_overrides_are_validated() {
+    // objects of the scenario are faster than the overridden speed of light
+    let source = "(
+        speed_of_light: 1.0,
+        objects: [Body(coord: (pos: (0.0, 0.0, 0.0), time: 0.0), velocity: (0.9, 0.0, 0.0))],
+    )";
+
+    let overrides = ScenarioOverrides {
+        speed_of_light: Some(0.5),
+        ..default()
+    };
+    assert!(matches!(
+        overrides.from_ron(source),
+        Err(ScenarioError::WorldLine(_))
+    ));
+}
+
+#[test]
+fn test_setup_inserts_resources() {
+    let path = std::env::temp_dir().join(format!("bevy_relativity_cli_{}.ron", std::process::id()));
+    std::fs::write(&path, SCENARIO).unwrap();
+
+    let cli =
+        Cli::try_parse_from(["demo".as_ref(), "--scenario".as_ref(), path.as_os_str()]).unwrap();
+    let scenario = cli
+        .simulation
+        .load_scenario("(speed_of_light: 2.0)")
+        .unwrap();
+    assert_eq!(scenario, Scenario::from_ron(SCENARIO).unwrap());
+
+    let mut app = App::new();
+    cli.simulation.setup(&mut app, scenario).unwrap();
+
+    let world = app.world();
+    assert!(world.contains_resource::<Scenario>());
+    assert_eq!(world.resource::<ScenarioFile>().path(), path);
+    assert!(!world.contains_resource::<InputRecorder>());
+    assert!(!world.contains_resource::<InputReplay>());
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn test_setup_replays_recorded_scenario() {
+    let path = |name: &str| {
+        std::env::temp_dir().join(format!(
+            "bevy_relativity_cli_{name}_{}.jsonl",
+            std::process::id()
+        ))
+    };
+    let (recording, rerecording) = (path("recording"), path("rerecording"));
+
+    let recorded_scenario = Scenario::from_ron(SCENARIO).unwrap();
+    let header = RecordingHeader {
+        scenario: recorded_scenario.clone(),
+        timestep: std::time::Duration::from_millis(10),
+    };
+    drop(InputRecorder::create(&recording, &header).unwrap());
+
+    // replay can't be combined with the scenario arguments
+    assert!(Cli::try_parse_from([
+        "demo".as_ref(),
+        "--replay".as_ref(),
+        recording.as_os_str(),
+        "-c".as_ref(),
+        "2".as_ref(),
+    ])
+    .is_err());
+
+    // recording over the replayed file would truncate it
+    let cli = Cli::try_parse_from([
+        "demo".as_ref(),
+        "--replay".as_ref(),
+        recording.as_os_str(),
+        "--record".as_ref(),
+        recording.as_os_str(),
+    ])
+    .unwrap();
+    let default_scenario = Scenario::from_ron("(speed_of_light: 2.0)").unwrap();
+    assert!(cli
+        .simulation
+        .setup(&mut App::new(), default_scenario.clone())
+        .is_err());
+
+    let cli = Cli::try_parse_from([
+        "demo".as_ref(),
+        "--replay".as_ref(),
+        recording.as_os_str(),
+        "--record".as_ref(),
+        rerecording.as_os_str(),
+    ])
+    .unwrap();
+    let mut app = App::new();
+    cli.simulation.setup(&mut app, default_scenario).unwrap();
+
+    let world = app.world();
+    assert_eq!(world.resource::<Scenario>(), &recorded_scenario);
+    assert_eq!(world.resource::<Time<Fixed>>().timestep(), header.timestep);
+    assert_eq!(world.resource::<InputReplay>().header(), Some(&header));
+
+    // the new recording repeats the replayed header
+    assert_eq!(
+        InputReplay::load(&rerecording).unwrap().header(),
+        Some(&header)
+    );
+
+    std::fs::remove_file(&recording).unwrap();
+    std::fs::remove_file(&rerecording).unwrap();
+}
@@ -0,0 +1,121 @@
+#![cfg(feature = "render")]
+
+use bevy::math::DVec3;
+use bevy::prelude::*;
+use bevy_relativity::*;
+use relativity::{Rapidity, SpacetimeEvent};
+
+/// Run one fixed timestep of the simulation with the current [`Time`].
+fn run_fixed_update(app: &mut App) {
+    app.world_mut().run_schedule(FixedUpdate);
+}
+
+fn spawn_object(app: &mut App, object: RelativeObject, observers: &[Entity]) -> Vec<Entity> {
+    let object = app.world_mut().spawn(object).id();
+
+    observers
+        .iter()
+        .map(|&observer| {
+            app.world_mut()
+                .spawn((
+                    ObjectImage { object, observer },
+                    Transform::default(),
+                    Visibility::default(),
+                ))
+                .id()
+        })
+        .collect()
+}
+
+#[test]
+fn test_plugin_updates_visible_objects() {
+    let mut app = App::new();
+    app.init_resource::<Time>().add_plugins(RelativityPlugin {
+        speed_of_light: 1.0,
+    });
+
+    let observer = app
+        .world_mut()
+        .spawn((
+            ObserverData::new(SpacetimeEvent::ZERO, 1.0),
+            VisibilityStats::default(),
+        ))
+        .id();
+
+    let visible = spawn_object(
+        &mut app,
+        RelativeObject::new(
+            0,
+            SpacetimeEvent::new(DVec3::X * 3.0).with_time(-10.0),
+            Rapidity::ZERO,
+        ),
+        &[observer],
+    )[0];
+
+    // light from the creation event has not reached the observer yet
+    let not_yet_visible = spawn_object(
+        &mut app,
+        RelativeObject::new(1, SpacetimeEvent::new(DVec3::Y * 3.0), Rapidity::ZERO),
+        &[observer],
+    )[0];
+
+    run_fixed_update(&mut app);
+
+    let world = app.world();
+
+    assert_eq!(
+        world.get::<Transform>(visible).unwrap().translation,
+        Vec3::X * 3.0
+    );
+    assert_eq!(world.get::<Visibility>(visible), Some(&Visibility::Visible));
+    assert_eq!(
+        world.get::<Visibility>(not_yet_visible),
+        Some(&Visibility::Hidden)
+    );
+
+    let stats = world.get::<VisibilityStats>(observer).unwrap();
+    assert_eq!(stats.visible, 1);
+    assert_eq!(stats.not_yet_visible, 1);
+    assert_eq!(stats.failures(), 0);
+}
+
+#[test]
+fn test_plugin_multiple_observers() {
+    let c = 1.0;
+
+    let mut app = App::new();
+    app.init_resource::<Time>()
+        .add_plugins(RelativityPlugin { speed_of_light: c });
+
+    let observer_at_rest = app
+        .world_mut()
+        .spawn(ObserverData::new(SpacetimeEvent::ZERO, 1.0))
+        .id();
+
+    let mut moving_observer_data = ObserverData::new(SpacetimeEvent::ZERO, 1.0);
+    moving_observer_data.rapidity = relativity::Rapidity::from_velocity(DVec3::X * 0.6, c);
+    moving_observer_data.record_event(c);
+    let moving_observer = app.world_mut().spawn(moving_observer_data).id();
+
+    let images = spawn_object(
+        &mut app,
+        RelativeObject::new(
+            0,
+            SpacetimeEvent::new(DVec3::X * 3.0).with_time(-10.0),
+            Rapidity::ZERO,
+        ),
+        &[observer_at_rest, moving_observer],
+    );
+
+    run_fixed_update(&mut app);
+
+    let world = app.world();
+
+    let at_rest = world.get::<Transform>(images[0]).unwrap().translation;
+    let moving = world.get::<Transform>(images[1]).unwrap().translation;
+
+    assert_eq!(at_rest, Vec3::X * 3.0);
+
+    // aberration: object ahead of the moving observer looks further away
+    assert!((moving.x - 6.0).abs() < 1e-5, "{moving}");
+}
@@ -0,0 +1,338 @@
+use bevy::math::DVec3;
+use bevy::prelude::*;
+use bevy_relativity::*;
+use relativity::SpacetimeEvent;
+
+/// Run one fixed timestep of the simulation with the current [`Time`].
+fn run_fixed_update(app: &mut App) {
+    app.world_mut().run_schedule(FixedUpdate);
+}
+
+#[test]
+fn test_plugin_observer_world_line() {
+    let c = 1.0;
+
+    let mut app = App::new();
+    app.init_resource::<Time>()
+        .add_plugins(RelativityPlugin { speed_of_light: c });
+
+    let observer_data = ObserverData::new(SpacetimeEvent::ZERO, 1.0);
+    let moving_observer = app
+        .world_mut()
+        .spawn((
+            RelativeObject::from_world_line(0, observer_data.world_line.clone()),
+            observer_data,
+        ))
+        .id();
+
+    let distant_observer = app
+        .world_mut()
+        .spawn(ObserverData::new(SpacetimeEvent::new(DVec3::X * 3.0), 1.0))
+        .id();
+
+    for step in 0..5 {
+        if step == 1 {
+            let mut observer = app
+                .world_mut()
+                .get_mut::<ObserverData>(moving_observer)
+                .unwrap();
+            observer.rapidity = relativity::Rapidity::from_velocity(DVec3::Y * 0.5, c);
+            observer.record_event(c);
+        }
+
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(std::time::Duration::from_secs(1));
+        run_fixed_update(&mut app);
+    }
+
+    let world = app.world();
+
+    let observer = world.get::<ObserverData>(moving_observer).unwrap();
+    assert_eq!(observer.world_line.events().len(), 2);
+    assert!((observer.world_line.last_event().velocity(c) - DVec3::Y * 0.5).length() < 1e-9);
+    assert!((observer.world_line.last_event().coord.time - 1.0).abs() < 1e-9);
+
+    let object = world.get::<RelativeObject>(moving_observer).unwrap();
+    assert_eq!(object.world_line.events().len(), 2);
+    assert!(object.visible_event(moving_observer).is_none());
+
+    // light emitted after the velocity change has reached the distant observer
+    let visible_event = object
+        .visible_event(distant_observer)
+        .expect("moving observer must be visible");
+    assert!(visible_event.proper_time > 0.0);
+    assert!((visible_event.velocity - DVec3::Y * 0.5).length() < 1e-9);
+}
+
+#[test]
+fn test_plugin_observer_world_line_at_high_rapidity() {
+    let c = 1.0;
+
+    let mut app = App::new();
+    app.init_resource::<Time>()
+        .add_plugins(RelativityPlugin { speed_of_light: c });
+
+    let observer_data = ObserverData::new(SpacetimeEvent::ZERO, 1.0);
+    let observer = app
+        .world_mut()
+        .spawn((
+            RelativeObject::from_world_line(0, observer_data.world_line.clone()),
+            observer_data,
+        ))
+        .id();
+
+    // thrust until the velocity rounds to the speed of light, then keep switching the engine
+    for step in 0..40 {
+        let mut observer_data = app.world_mut().get_mut::<ObserverData>(observer).unwrap();
+        let thrust = if step <= 30 || step % 2 == 0 {
+            DVec3::X * observer_data.acceleration
+        } else {
+            DVec3::ZERO
+        };
+        observer_data.set_proper_acceleration(thrust, c);
+
+        app.world_mut()
+            .resource_mut::<Time>()
+            .advance_by(std::time::Duration::from_secs(1));
+        run_fixed_update(&mut app);
+    }
+
+    let world = app.world();
+
+    let observer_data = world.get::<ObserverData>(observer).unwrap();
+    assert_eq!(observer_data.velocity(c), DVec3::X * c);
+    assert!((observer_data.rapidity.magnitude - 35.0).abs() < 1e-9);
+
+    // the initial event and every switch of the engine after the first 30 s
+    let events = observer_data.world_line.events();
+    assert_eq!(events.len(), 10);
+    assert!((events.last().object_proper_time - 39.0).abs() < 1e-6);
+    assert!((events.last().rapidity.magnitude - 35.0).abs() < 1e-9);
+
+    let object = world.get::<RelativeObject>(observer).unwrap();
+    assert_eq!(object.world_line.events().len(), events.len());
+    assert_eq!(object.world_line.last_event().coord, events.last().coord);
+}
+
+#[test]
+fn test_observer_advance_does_not_depend_on_step() {
+    let c = 1.0;
+
+    let accelerating_observer = || {
+        let mut observer = ObserverData::new(SpacetimeEvent::ZERO, 2.0);
+        observer.rapidity = relativity::Rapidity::from_velocity(DVec3::X * 0.5, c);
+        observer.record_event(c);
+        observer.set_proper_acceleration(DVec3::Y * observer.acceleration, c);
+
+        observer
+    };
+
+    let mut single = accelerating_observer();
+    single.advance(3.0, c);
+
+    let mut stepped = accelerating_observer();
+    for _ in 0..3000 {
+        stepped.advance(0.001, c);
+    }
+
+    assert!((stepped.proper_time - single.proper_time).abs() < 1e-9);
+    assert!((stepped.coord.pos - single.coord.pos).length() < 1e-9);
+    assert!((stepped.coord.time - single.coord.time).abs() < 1e-9);
+
+    // the observer stays on its world line
+    let event = stepped
+        .world_line
+        .event_at_proper_time(stepped.proper_time, c)
+        .unwrap();
+    assert!((event.coord.pos - stepped.coord.pos).length() < 1e-6);
+    assert!((event.coord.time - stepped.coord.time).abs() < 1e-6);
+}
+
+fn controlled_observer_app(c: f64) -> (App, Entity) {
+    let mut app = App::new();
+    app.init_resource::<Time>()
+        .add_plugins(RelativityPlugin { speed_of_light: c });
+
+    let observer = app
+        .world_mut()
+        .spawn((
+            ObserverData::new(SpacetimeEvent::ZERO, 2.0),
+            ControlledObserver,
+        ))
+        .id();
+
+    (app, observer)
+}
+
+fn advance_fixed_step(app: &mut App) {
+    app.world_mut()
+        .resource_mut::<Time>()
+        .advance_by(std::time::Duration::from_secs_f64(1.0 / 64.0));
+    run_fixed_update(app);
+}
+
+#[test]
+fn test_plugin_replays_recorded_input() {
+    let c = 1.0;
+    let path = std::env::temp_dir().join(format!(
+        "bevy_relativity_replay_{}.jsonl",
+        std::process::id()
+    ));
+
+    let inputs = (0..30)
+        .map(|step| ControlInput {
+            thrust: match step {
+                0..=9 => DVec3::X,
+                10..=19 => DVec3::new(1.0, 1.0, 0.0).normalize(),
+                _ => DVec3::ZERO,
+            },
+            action: step == 15,
+        })
+        .collect::<Vec<_>>();
+
+    let header = RecordingHeader {
+        scenario: Scenario::from_ron("(speed_of_light: 1.0)").unwrap(),
+        timestep: std::time::Duration::from_secs_f64(1.0 / 64.0),
+    };
+
+    let (mut live, live_observer) = controlled_observer_app(c);
+    live.insert_resource(InputRecorder::create(&path, &header).unwrap());
+
+    for input in &inputs {
+        live.world_mut().resource_mut::<PendingControlInput>().0 = *input;
+        advance_fixed_step(&mut live);
+    }
+
+    let replay = InputReplay::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(replay.remaining(), inputs.len());
+    assert_eq!(replay.header(), Some(&header));
+
+    let (mut replayed, replayed_observer) = controlled_observer_app(c);
+    replayed.insert_resource(replay);
+
+    // user input is ignored during the replay
+    replayed
+        .world_mut()
+        .resource_mut::<PendingControlInput>()
+        .0
+        .thrust = DVec3::Y;
+
+    for _ in &inputs {
+        advance_fixed_step(&mut replayed);
+    }
+
+    let live = live.world().get::<ObserverData>(live_observer).unwrap();
+    let replayed = replayed
+        .world()
+        .get::<ObserverData>(replayed_observer)
+        .unwrap();
+
+    assert!(live.coord.pos.x > 0.0);
+    assert_eq!(live.coord, replayed.coord);
+    assert_eq!(live.rapidity, replayed.rapidity);
+    assert_eq!(live.proper_time, replayed.proper_time);
+    assert_eq!(
+        live.world_line.events().len(),
+        replayed.world_line.events().len()
+    );
+}
+
+#[test]
+fn test_plugin_destroys_objects_on_action() {
+    let c = 1.0;
+    let (mut app, _) = controlled_observer_app(c);
+
+    let object = |x: f64| {
+        RelativeObject::new(
+            0,
+            SpacetimeEvent::new(DVec3::X * x).with_time(-10.0),
+            relativity::Rapidity::ZERO,
+        )
+    };
+    let near = app.world_mut().spawn(object(DESTRUCTION_RADIUS * 0.5)).id();
+    let far = app.world_mut().spawn(object(DESTRUCTION_RADIUS * 2.0)).id();
+
+    app.insert_resource(InputReplay::new([
+        ControlInput::default(),
+        ControlInput {
+            action: true,
+            ..default()
+        },
+    ]));
+
+    advance_fixed_step(&mut app);
+    advance_fixed_step(&mut app);
+
+    let world = app.world();
+    let near = &world.get::<RelativeObject>(near).unwrap().world_line;
+    let far = &world.get::<RelativeObject>(far).unwrap().world_line;
+
+    let end = near.end_event().expect("near object must be destroyed");
+    // objects are destroyed before the observer advances in the same timestep
+    assert!((end.coord.time - 1.0 / 64.0).abs() < 1e-9);
+    assert!(far.end_event().is_none());
+}
+
+#[test]
+fn test_plugin_destroys_objects_around_every_controlled_observer() {
+    let c = 1.0;
+
+    let mut app = App::new();
+    app.init_resource::<Time>()
+        .add_plugins(RelativityPlugin { speed_of_light: c });
+
+    let action = || {
+        InputReplay::new([ControlInput {
+            action: true,
+            ..default()
+        }])
+    };
+
+    let object = |x: f64| {
+        RelativeObject::new(
+            0,
+            SpacetimeEvent::new(DVec3::X * x).with_time(-10.0),
+            relativity::Rapidity::ZERO,
+        )
+    };
+    let first = app.world_mut().spawn(object(1.0)).id();
+    let second = app.world_mut().spawn(object(21.0)).id();
+    let far = app.world_mut().spawn(object(11.0)).id();
+
+    // the action without controlled observers does nothing
+    app.insert_resource(action());
+    advance_fixed_step(&mut app);
+    assert!(app
+        .world()
+        .get::<RelativeObject>(first)
+        .unwrap()
+        .world_line
+        .end_event()
+        .is_none());
+
+    for x in [0.0, 20.0] {
+        app.world_mut().spawn((
+            ObserverData::new(SpacetimeEvent::new(DVec3::X * x).with_time(1.0 / 64.0), 2.0),
+            ControlledObserver,
+        ));
+    }
+
+    app.insert_resource(action());
+    advance_fixed_step(&mut app);
+
+    let world = app.world();
+    let end_event = |entity| {
+        world
+            .get::<RelativeObject>(entity)
+            .unwrap()
+            .world_line
+            .end_event()
+            .copied()
+    };
+
+    assert!(end_event(first).is_some());
+    assert!(end_event(second).is_some());
+    assert!(end_event(far).is_none());
+}
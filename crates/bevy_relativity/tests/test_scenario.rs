@@ -0,0 +1,229 @@
+use bevy::math::DVec3;
+use bevy::prelude::*;
+use bevy_relativity::*;
+use relativity::SpacetimeEvent;
+use std::time::{Duration, SystemTime};
+
+const EPSILON: f64 = 1e-9;
+
+const SCENARIO: &str = r#"
+(
+    speed_of_light: 2.0,
+    observers: [
+        (
+            coord: (pos: (1.0, 0.0, 0.0), time: 5.0),
+            velocity: (0.0, 1.0, 0.0),
+            acceleration: 3.0,
+        ),
+    ],
+    objects: [
+        Grid(
+            center: (pos: (10.0, 0.0, 0.0), time: -100.0),
+            count: (3, 2, 1),
+            spacing: (2.0, 4.0, 0.0),
+        ),
+        Body(
+            coord: (pos: (0.0, 0.0, 0.0), time: 0.0),
+            velocity: (1.0, 0.0, 0.0),
+            maneuvers: [
+                (proper_time: 4.0, proper_acceleration: (0.0, 0.0, 0.0)),
+                (proper_time: 0.0, proper_acceleration: (-0.5, 0.0, 0.0)),
+            ],
+        ),
+        Cloud(
+            center: (pos: (0.0, 0.0, 0.0), time: 0.0),
+            velocity: (1.5, 0.0, 0.0),
+            count: 10,
+            extent: (5.0, 5.0, 0.0),
+            velocity_spread: (1.0, 1.0, 0.0),
+            seed: 1,
+        ),
+    ],
+)
+"#;
+
+#[test]
+fn test_scenario_generators() {
+    let scenario = Scenario::from_ron(SCENARIO).unwrap();
+    let c = scenario.speed_of_light;
+
+    assert_eq!(scenario.observers[0].acceleration, 3.0);
+
+    let bodies = scenario.bodies();
+    assert_eq!(bodies.len(), 3 * 2 + 1 + 10);
+
+    // grid is centered at its center
+    let grid = &bodies[..6];
+    let positions = grid.iter().map(|body| body.coord.pos).collect::<Vec<_>>();
+    assert_eq!(positions[0], DVec3::new(8.0, -2.0, 0.0));
+    assert_eq!(positions[5], DVec3::new(12.0, 2.0, 0.0));
+    assert_eq!(
+        positions.iter().sum::<DVec3>() / positions.len() as f64,
+        DVec3::new(10.0, 0.0, 0.0)
+    );
+    assert!(grid.iter().all(|body| body.coord.time == -100.0));
+
+    // cloud is inside its extent and moves slower than light
+    for body in &bodies[7..] {
+        let relative = body.coord.pos / DVec3::new(5.0, 5.0, 1.0);
+        assert!(relative.length() <= 1.0);
+        assert!(body.velocity.length() < c);
+        assert!(body.velocity.x > 0.0);
+    }
+
+    assert_eq!(scenario.world_lines().unwrap().len(), bodies.len());
+}
+
+#[test]
+fn test_body_maneuvers() {
+    let scenario = Scenario::from_ron(SCENARIO).unwrap();
+    let c = scenario.speed_of_light;
+
+    let world_line = scenario.bodies()[6].world_line(c).unwrap();
+    let events = world_line.events();
+
+    // maneuvers are sorted, maneuver at the start changes the first event
+    assert_eq!(events.len(), 2);
+    assert_eq!(
+        events.first().proper_acceleration,
+        DVec3::new(-0.5, 0.0, 0.0)
+    );
+    assert!((events.last().object_proper_time - 4.0).abs() < EPSILON);
+    assert_eq!(events.last().proper_acceleration, DVec3::ZERO);
+
+    // the object decelerated to rest and flies back
+    let expected = events.first().advance(4.0, c);
+    assert!((events.last().coord.pos - expected.coord.pos).length() < EPSILON);
+    assert!(events.last().velocity(c).x < 0.0);
+}
+
+#[test]
+fn test_cloud_is_reproducible() {
+    let cloud = |seed| ObjectGenerator::Cloud {
+        center: SpacetimeEvent::ZERO,
+        velocity: DVec3::ZERO,
+        count: 5,
+        extent: DVec3::ONE,
+        velocity_spread: DVec3::splat(0.5),
+        seed,
+        maneuvers: Vec::new(),
+    };
+
+    assert_eq!(cloud(3).bodies(1.0), cloud(3).bodies(1.0));
+    assert_ne!(cloud(3).bodies(1.0), cloud(4).bodies(1.0));
+}
+
+#[test]
+fn test_invalid_scenarios() {
+    let error = Scenario::from_ron("(speed_of_light: 0.0)").unwrap_err();
+    assert!(matches!(error, ScenarioError::InvalidSpeedOfLight { .. }));
+
+    let error = Scenario::from_ron("(speed_of_light: 1.0, objects: [Grid()])").unwrap_err();
+    assert!(matches!(error, ScenarioError::Parse(_)));
+
+    let error =
+        Scenario::from_ron("(speed_of_light: 1.0, observers: [(), (velocity: (1.0, 0.0, 0.0))])")
+            .unwrap_err();
+    assert!(matches!(error, ScenarioError::WorldLine(_)));
+
+    let error = Scenario::from_ron(
+        "(
+            speed_of_light: 1.0,
+            objects: [Body(
+                coord: (pos: (0.0, 0.0, 0.0), time: 0.0),
+                maneuvers: [(proper_time: -1.0, proper_acceleration: (1.0, 0.0, 0.0))],
+            )],
+        )",
+    )
+    .unwrap_err();
+    assert!(matches!(error, ScenarioError::InvalidManeuver { .. }));
+}
+
+#[test]
+fn test_bundled_scenarios_are_valid() {
+    let crates = std::path::Path::new(env!("CARGO_MANIFEST_DIR")).join("..");
+
+    let mut count = 0;
+
+    for entry in std::fs::read_dir(crates).unwrap() {
+        let Ok(scenarios) = std::fs::read_dir(entry.unwrap().path().join("scenarios")) else {
+            continue;
+        };
+
+        for scenario in scenarios {
+            let path = scenario.unwrap().path();
+
+            if let Err(error) = Scenario::load(&path) {
+                panic!("{}: {error}", path.display());
+            }
+            count += 1;
+        }
+    }
+
+    assert!(count > 0);
+}
+
+#[test]
+fn test_plugin_applies_changed_scenario() {
+    let mut app = App::new();
+    app.init_resource::<Time>()
+        .add_plugins(RelativityPlugin {
+            speed_of_light: 1.0,
+        })
+        .insert_resource(Scenario::from_ron("(speed_of_light: 1.0)").unwrap());
+
+    let observer = app
+        .world_mut()
+        .spawn(ObserverData::new(SpacetimeEvent::ZERO, 1.0))
+        .id();
+    app.world_mut().run_schedule(PreUpdate);
+
+    let object = app.world_mut().spawn(ScenarioObject).id();
+    app.world_mut()
+        .get_mut::<ObserverData>(observer)
+        .unwrap()
+        .advance(10.0, 1.0);
+
+    // nothing changes until the scenario changes
+    app.world_mut().run_schedule(PreUpdate);
+    assert!(app.world().get_entity(object).is_some());
+
+    app.insert_resource(Scenario::from_ron(SCENARIO).unwrap());
+    app.world_mut().run_schedule(PreUpdate);
+
+    let world = app.world();
+    assert_eq!(world.resource::<RelativeSettings>().speed_of_light, 2.0);
+    assert!(world.get_entity(object).is_none());
+
+    let observer = world.get::<ObserverData>(observer).unwrap();
+    assert_eq!(observer.coord, SpacetimeEvent::new(DVec3::X).with_time(5.0));
+    assert!((observer.velocity(2.0) - DVec3::Y).length() < EPSILON);
+    assert_eq!(observer.acceleration, 3.0);
+    assert_eq!(observer.world_line.events().len(), 1);
+}
+
+#[test]
+fn test_scenario_file_reloads_when_modified() {
+    let path = std::env::temp_dir().join(format!(
+        "bevy_relativity_scenario_{}.ron",
+        std::process::id()
+    ));
+    std::fs::write(&path, "(speed_of_light: 1.0)").unwrap();
+
+    let mut file = ScenarioFile::new(&path);
+    assert!(file.reload_if_modified().is_none());
+
+    std::fs::write(&path, SCENARIO).unwrap();
+    std::fs::File::options()
+        .write(true)
+        .open(&path)
+        .unwrap()
+        .set_modified(SystemTime::now() + Duration::from_secs(10))
+        .unwrap();
+
+    let scenario = file.reload_if_modified().unwrap().unwrap();
+    assert_eq!(scenario.speed_of_light, 2.0);
+    assert!(file.reload_if_modified().is_none());
+
+    std::fs::remove_file(&path).unwrap();
+}
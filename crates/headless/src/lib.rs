@@ -0,0 +1,5 @@
+mod observation;
+mod simulation;
+
+pub use observation::*;
+pub use simulation::*;
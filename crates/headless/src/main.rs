@@ -0,0 +1,111 @@
+use bevy_relativity::{InputReplay, SimulationArgs};
+use clap::error::ErrorKind;
+use clap::{CommandFactory, Parser};
+use headless::*;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+use std::time::Duration;
+
+/// Scenario without objects, replaced by the recorded one on --replay.
+const EMPTY_SCENARIO: &str = "(speed_of_light: 1.0)";
+
+/// Run the scenario without rendering and write what the observers see after every fixed timestep.
+#[derive(Debug, Parser)]
+struct Cli {
+    #[command(flatten)]
+    simulation: SimulationArgs,
+
+    /// Proper time of the observer to simulate [default: until the end of --replay]
+    #[arg(short, long)]
+    duration: Option<f64>,
+
+    /// Fixed timestep in seconds of the observer's proper time [default: from --replay]
+    #[arg(long, default_value_t = DEFAULT_TIMESTEP.as_secs_f64(), conflicts_with = "replay")]
+    timestep: f64,
+
+    /// Output file [default: stdout]
+    #[arg(short, long, value_name = "PATH")]
+    output: Option<PathBuf>,
+
+    #[arg(short, long, value_enum, default_value_t)]
+    format: ObservationFormat,
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    if let Err(error) = run(&cli) {
+        Cli::command().error(ErrorKind::Io, error).exit();
+    }
+}
+
+fn run(cli: &Cli) -> Result<(), Box<dyn std::error::Error>> {
+    if !(cli.timestep.is_finite() && cli.timestep > 0.0) {
+        Cli::command()
+            .error(ErrorKind::InvalidValue, "--timestep must be positive")
+            .exit();
+    }
+
+    if cli
+        .duration
+        .is_some_and(|duration| !(duration.is_finite() && duration >= 0.0))
+    {
+        Cli::command()
+            .error(
+                ErrorKind::InvalidValue,
+                "--duration must be finite and not negative",
+            )
+            .exit();
+    }
+
+    if cli.simulation.scenario.is_none() && cli.simulation.replay.is_none() {
+        Cli::command()
+            .error(
+                ErrorKind::MissingRequiredArgument,
+                "either --scenario or --replay is required",
+            )
+            .exit();
+    }
+
+    // a replay restarts the recorded scenario with the recorded timestep, so this one is only a
+    // placeholder without --scenario
+    let scenario = cli.simulation.load_scenario(EMPTY_SCENARIO)?;
+
+    let mut simulation = HeadlessSimulation::from_args(
+        &cli.simulation,
+        scenario,
+        Duration::from_secs_f64(cli.timestep),
+    )?;
+
+    let replay = simulation.app_mut().world().get_resource::<InputReplay>();
+
+    let steps = match (cli.duration, replay) {
+        (Some(duration), _) => (duration / simulation.timestep().as_secs_f64()).round() as u64,
+        (None, Some(replay)) => replay.remaining() as u64,
+        (None, None) => Cli::command()
+            .error(
+                ErrorKind::MissingRequiredArgument,
+                "either --duration or --replay is required",
+            )
+            .exit(),
+    };
+
+    let output: Box<dyn Write> = match &cli.output {
+        Some(path) => Box::new(BufWriter::new(File::create(path)?)),
+        None => Box::new(BufWriter::new(io::stdout().lock())),
+    };
+    let mut writer = ObservationWriter::new(output, cli.format)?;
+
+    for _ in 0..steps {
+        simulation.advance();
+
+        for observation in simulation.observations() {
+            writer.write(&observation)?;
+        }
+    }
+
+    writer.finish()?;
+
+    Ok(())
+}
@@ -0,0 +1,89 @@
+use bevy::math::DVec3;
+use serde::{Deserialize, Serialize};
+use std::io::{self, Write};
+
+/// Relative object as one of the observers sees it after one fixed timestep.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Observation {
+    /// Number of the fixed timestep, starting from 1.
+    pub step: u64,
+    /// Index of the observer in the scenario.
+    pub observer: u32,
+    /// Proper time of the observer.
+    pub observer_proper_time: f64,
+    /// Id of the relative object.
+    pub object: u32,
+    /// Visible position of the object in the observer's rest frame relative to the observer.
+    pub position: DVec3,
+    /// Proper time of the object at the visible event.
+    pub object_proper_time: f64,
+    /// Velocity of the object at the visible event in the observer's rest frame.
+    pub velocity: DVec3,
+    /// Ratio of the observed frequency to the emitted one.
+    pub doppler_factor: f64,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, clap::ValueEnum)]
+pub enum ObservationFormat {
+    /// Comma separated values with a header, vectors are split into components
+    #[default]
+    Csv,
+    /// JSON object per line
+    Jsonl,
+}
+
+const CSV_HEADER: &str = "step,observer,observer_proper_time,object,x,y,z,object_proper_time,vx,\
+                          vy,vz,doppler_factor";
+
+/// Writes [`Observation`]s in the given format.
+#[derive(Debug)]
+pub struct ObservationWriter<W: Write> {
+    writer: W,
+    format: ObservationFormat,
+}
+
+impl<W: Write> ObservationWriter<W> {
+    /// Create the writer, the header is written immediately.
+    pub fn new(mut writer: W, format: ObservationFormat) -> io::Result<Self> {
+        if format == ObservationFormat::Csv {
+            writeln!(writer, "{CSV_HEADER}")?;
+        }
+
+        Ok(Self { writer, format })
+    }
+
+    pub fn write(&mut self, observation: &Observation) -> io::Result<()> {
+        match self.format {
+            ObservationFormat::Csv => {
+                let Observation {
+                    step,
+                    observer,
+                    observer_proper_time,
+                    object,
+                    position,
+                    object_proper_time,
+                    velocity,
+                    doppler_factor,
+                } = observation;
+
+                writeln!(
+                    self.writer,
+                    "{step},{observer},{observer_proper_time},{object},{},{},{},\
+                     {object_proper_time},{},{},{},{doppler_factor}",
+                    position.x, position.y, position.z, velocity.x, velocity.y, velocity.z,
+                )
+            }
+            ObservationFormat::Jsonl => {
+                serde_json::to_writer(&mut self.writer, observation)?;
+                writeln!(self.writer)
+            }
+        }
+    }
+
+    /// Flush and return the underlying writer.
+    pub fn finish(mut self) -> io::Result<W> {
+        self.writer.flush()?;
+
+        Ok(self.writer)
+    }
+}
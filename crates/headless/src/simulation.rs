@@ -0,0 +1,178 @@
+use crate::*;
+use bevy::prelude::*;
+use bevy_relativity::{
+    ControlledObserver,
+    ObserverData,
+    RelativeObject,
+    RelativeSettings,
+    RelativityPlugin,
+    Scenario,
+    ScenarioError,
+    ScenarioObserver,
+    SimulationArgs,
+};
+use std::time::Duration;
+
+/// Fixed timestep of Bevy's [`FixedUpdate`] by default, so trips recorded by the demos are
+/// replayed the same way.
+pub const DEFAULT_TIMESTEP: Duration = Duration::from_micros(15625);
+
+/// Simulation of the [`Scenario`] with [`RelativityPlugin`] and without rendering, advanced
+/// manually one fixed timestep at a time.
+///
+/// Every observer of the scenario is spawned the same way as in the demos: observers are relative
+/// objects with ids starting from 0 and see each other, ids of the scenario objects follow them.
+/// The first observer is the [`ControlledObserver`], so it follows [`InputReplay`] (thrust and
+/// destruction of objects) if the resource is inserted into [`HeadlessSimulation::app_mut`].
+///
+/// [`InputReplay`]: bevy_relativity::InputReplay
+pub struct HeadlessSimulation {
+    app: App,
+    observers: Vec<Entity>,
+    timestep: Duration,
+    step: u64,
+}
+
+impl HeadlessSimulation {
+    pub fn new(scenario: &Scenario, timestep: Duration) -> Result<Self, ScenarioError> {
+        let mut app = App::new();
+        app.init_resource::<Time>();
+
+        Self::spawn(app, scenario, timestep)
+    }
+
+    /// Simulation set up from the command-line arguments with [`SimulationArgs::setup`], so a
+    /// replay replaces `scenario` and `timestep` with the recorded ones.
+    pub fn from_args(
+        args: &SimulationArgs,
+        scenario: Scenario,
+        timestep: Duration,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let mut app = App::new();
+        app.init_resource::<Time>()
+            .insert_resource(Time::<Fixed>::from_duration(timestep));
+
+        args.setup(&mut app, scenario)?;
+
+        let scenario = app.world().resource::<Scenario>().clone();
+        let timestep = app.world().resource::<Time<Fixed>>().timestep();
+
+        Ok(Self::spawn(app, &scenario, timestep)?)
+    }
+
+    /// Add [`RelativityPlugin`] to `app` and spawn the observers and objects of `scenario`.
+    fn spawn(mut app: App, scenario: &Scenario, timestep: Duration) -> Result<Self, ScenarioError> {
+        let c = scenario.speed_of_light;
+        let world_lines = scenario.world_lines()?;
+
+        app.add_plugins(RelativityPlugin { speed_of_light: c });
+
+        let observers = (0..scenario.observers.len())
+            .map(|index| {
+                let observer_data = scenario.observer(index).observer_data(c);
+
+                let mut observer = app.world_mut().spawn((
+                    RelativeObject::from_world_line(index as u32, observer_data.world_line.clone()),
+                    observer_data,
+                    ScenarioObserver(index),
+                ));
+
+                if index == 0 {
+                    observer.insert(ControlledObserver);
+                }
+
+                observer.id()
+            })
+            .collect::<Vec<_>>();
+
+        for (id, world_line) in (observers.len() as u32..).zip(world_lines) {
+            app.world_mut()
+                .spawn(RelativeObject::from_world_line(id, world_line));
+        }
+
+        Ok(Self {
+            app,
+            observers,
+            timestep,
+            step: 0,
+        })
+    }
+
+    pub fn app_mut(&mut self) -> &mut App {
+        &mut self.app
+    }
+
+    /// Fixed timestep of the simulation, the recorded one for a replay.
+    pub fn timestep(&self) -> Duration {
+        self.timestep
+    }
+
+    /// Number of fixed timesteps simulated so far.
+    pub fn step(&self) -> u64 {
+        self.step
+    }
+
+    /// Observer with the given index in the scenario, the controlled one has index 0.
+    pub fn observer(&self, index: usize) -> &ObserverData {
+        self.app
+            .world()
+            .get::<ObserverData>(self.observers[index])
+            .expect("observers are never despawned")
+    }
+
+    /// Number of observers in the scenario.
+    pub fn observer_count(&self) -> usize {
+        self.observers.len()
+    }
+
+    /// Run one fixed timestep.
+    pub fn advance(&mut self) {
+        self.app
+            .world_mut()
+            .resource_mut::<Time>()
+            .advance_by(self.timestep);
+        self.app.world_mut().run_schedule(FixedUpdate);
+        self.step += 1;
+    }
+
+    /// Objects visible to every observer after the last timestep, ordered by the observer's index
+    /// and by the objects' ids.
+    pub fn observations(&mut self) -> Vec<Observation> {
+        let c = self
+            .app
+            .world()
+            .resource::<RelativeSettings>()
+            .speed_of_light;
+        let step = self.step;
+
+        let mut objects = self.app.world_mut().query::<&RelativeObject>();
+        let mut observations = Vec::new();
+
+        for (index, &observer) in self.observers.iter().enumerate() {
+            let observer_proper_time = self.observer(index).proper_time;
+
+            let mut visible = objects
+                .iter(self.app.world())
+                .filter_map(|object| {
+                    let visible_event = object.visible_event(observer)?;
+
+                    Some(Observation {
+                        step,
+                        observer: index as u32,
+                        observer_proper_time,
+                        object: object.id,
+                        position: visible_event.relative_coord.pos,
+                        object_proper_time: visible_event.proper_time,
+                        velocity: visible_event.relative_velocity,
+                        doppler_factor: visible_event.doppler_factor(c),
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            visible.sort_by_key(|observation| observation.object);
+            observations.append(&mut visible);
+        }
+
+        observations
+    }
+}
@@ -0,0 +1,208 @@
+use bevy::math::DVec3;
+use bevy_relativity::{ControlInput, InputReplay, Scenario, SimulationArgs};
+use headless::*;
+use relativity::SpacetimeEvent;
+use std::time::Duration;
+
+const EPSILON: f64 = 1e-9;
+
+/// Two objects at rest on the x axis, created long before the observer starts.
+fn scenario(observer_velocity: f64) -> Scenario {
+    Scenario::from_ron(&format!(
+        "(
+            speed_of_light: 1.0,
+            observers: [(velocity: ({observer_velocity}, 0.0, 0.0))],
+            objects: [
+                Grid(
+                    center: (pos: (10.0, 0.0, 0.0), time: -1000.0),
+                    count: (2, 1, 1),
+                    spacing: (4.0, 0.0, 0.0),
+                ),
+            ],
+        )"
+    ))
+    .unwrap()
+}
+
+fn run(scenario: &Scenario, steps: usize, inputs: &[ControlInput]) -> Vec<Observation> {
+    let mut simulation = HeadlessSimulation::new(scenario, DEFAULT_TIMESTEP).unwrap();
+    simulation
+        .app_mut()
+        .insert_resource(InputReplay::new(inputs.iter().copied()));
+
+    (0..steps)
+        .flat_map(|_| {
+            simulation.advance();
+            simulation.observations()
+        })
+        .collect()
+}
+
+#[test]
+fn test_observations_of_objects_at_rest() {
+    let observations = run(&scenario(0.0), 2, &[]);
+
+    assert_eq!(observations.len(), 4);
+    assert_eq!(
+        observations
+            .iter()
+            .map(|o| (o.step, o.object))
+            .collect::<Vec<_>>(),
+        // ids of the objects follow the observer's id
+        [(1, 1), (1, 2), (2, 1), (2, 2)]
+    );
+
+    let observation = observations[0];
+    assert!((observation.observer_proper_time - DEFAULT_TIMESTEP.as_secs_f64()).abs() < EPSILON);
+    assert!((observation.position - DVec3::new(8.0, 0.0, 0.0)).length() < EPSILON);
+    assert_eq!(observation.velocity, DVec3::ZERO);
+    assert!((observation.doppler_factor - 1.0).abs() < EPSILON);
+
+    // the light from the object was emitted 8 seconds ago
+    let proper_time = 1000.0 + observation.observer_proper_time - 8.0;
+    assert!((observation.object_proper_time - proper_time).abs() < 1e-6);
+}
+
+#[test]
+fn test_observations_of_approaching_objects() {
+    let beta = 0.6;
+    let observations = run(&scenario(beta), 1, &[]);
+
+    for observation in observations {
+        assert!((observation.velocity - DVec3::new(-beta, 0.0, 0.0)).length() < EPSILON);
+
+        // objects ahead are blueshifted
+        let doppler_factor = ((1.0 + beta) / (1.0 - beta)).sqrt();
+        assert!((observation.doppler_factor - doppler_factor).abs() < 1e-6);
+    }
+}
+
+#[test]
+fn test_replayed_thrust_changes_observations() {
+    let thrust = vec![
+        ControlInput {
+            thrust: DVec3::X,
+            action: false,
+        };
+        32
+    ];
+
+    let coasting = run(&scenario(0.0), 64, &[]);
+    let thrusting = run(&scenario(0.0), 64, &thrust);
+    assert_eq!(coasting.len(), thrusting.len());
+
+    let (coasting, thrusting) = (coasting.last().unwrap(), thrusting.last().unwrap());
+    assert!(thrusting.velocity.x < 0.0);
+    assert!(thrusting.doppler_factor > 1.0);
+    // light of approaching objects was emitted when they were farther away
+    assert!(thrusting.position.x > coasting.position.x);
+
+    // the same inputs always produce the same observations
+    assert_eq!(run(&scenario(0.0), 64, &thrust).last(), Some(thrusting));
+}
+
+#[test]
+fn test_every_observer_sees_objects_and_other_observers() {
+    let mut scenario = scenario(0.0);
+    scenario.observers.push(scenario.observer(0));
+    // the second observer starts earlier, so only the first one sees it
+    scenario.observers[1].coord = SpacetimeEvent::new(DVec3::new(0.0, -2.0, 0.0)).with_time(-10.0);
+
+    let mut simulation = HeadlessSimulation::new(&scenario, DEFAULT_TIMESTEP).unwrap();
+    assert_eq!(simulation.observer_count(), 2);
+
+    simulation.advance();
+    let observations = simulation.observations();
+
+    assert_eq!(
+        observations
+            .iter()
+            .map(|o| (o.observer, o.object))
+            .collect::<Vec<_>>(),
+        [(0, 1), (0, 2), (0, 3), (1, 2), (1, 3)]
+    );
+
+    // the light from the second observer was emitted 2 seconds ago
+    let other = observations[0];
+    assert!((other.position - DVec3::new(0.0, -2.0, 0.0)).length() < EPSILON);
+    let proper_time = 10.0 + DEFAULT_TIMESTEP.as_secs_f64() - 2.0;
+    assert!((other.object_proper_time - proper_time).abs() < 1e-6);
+}
+
+#[test]
+fn test_simulation_replays_recording_from_args() {
+    let path = std::env::temp_dir().join(format!("headless_replay_{}.jsonl", std::process::id()));
+    let timestep = Duration::from_millis(50);
+    let thrust = ControlInput {
+        thrust: DVec3::X,
+        action: false,
+    };
+
+    let record = SimulationArgs {
+        record: Some(path.clone()),
+        ..Default::default()
+    };
+    let mut recorded = HeadlessSimulation::from_args(&record, scenario(0.0), timestep).unwrap();
+    recorded
+        .app_mut()
+        .insert_resource(InputReplay::new(vec![thrust; 8]));
+    for _ in 0..8 {
+        recorded.advance();
+    }
+    drop(recorded);
+
+    // the recorded scenario and timestep replace the given ones
+    let replay = SimulationArgs {
+        replay: Some(path.clone()),
+        ..Default::default()
+    };
+    let mut replayed =
+        HeadlessSimulation::from_args(&replay, scenario(0.5), DEFAULT_TIMESTEP).unwrap();
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(replayed.timestep(), timestep);
+    for _ in 0..8 {
+        replayed.advance();
+    }
+
+    let observer = replayed.observer(0);
+    assert!((observer.proper_time - 0.4).abs() < EPSILON);
+    assert!(observer.velocity(1.0).x > 0.0);
+
+    // the recording and the replay can't be the same file
+    let same = SimulationArgs {
+        record: Some(path.clone()),
+        replay: Some(path),
+        ..Default::default()
+    };
+    assert!(HeadlessSimulation::from_args(&same, scenario(0.0), timestep).is_err());
+}
+
+#[test]
+fn test_observation_formats() {
+    let observations = run(&scenario(0.0), 1, &[]);
+
+    let mut writer = ObservationWriter::new(Vec::new(), ObservationFormat::Csv).unwrap();
+    for observation in &observations {
+        writer.write(observation).unwrap();
+    }
+    let csv = String::from_utf8(writer.finish().unwrap()).unwrap();
+
+    let lines = csv.lines().collect::<Vec<_>>();
+    assert_eq!(lines.len(), observations.len() + 1);
+    assert_eq!(lines[0].split(',').count(), lines[1].split(',').count());
+    assert!(lines[0].starts_with("step,"));
+    assert!(lines[1].starts_with("1,"));
+
+    let mut writer = ObservationWriter::new(Vec::new(), ObservationFormat::Jsonl).unwrap();
+    for observation in &observations {
+        writer.write(observation).unwrap();
+    }
+    let jsonl = String::from_utf8(writer.finish().unwrap()).unwrap();
+
+    let parsed = jsonl
+        .lines()
+        .map(|line| serde_json::from_str::<Observation>(line).unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(parsed, observations);
+}
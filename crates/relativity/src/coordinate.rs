@@ -1,5 +1,5 @@
 use crate::*;
-use bevy::math::{DMat4, DVec3, DVec4};
+use glam::{DMat4, DVec3, DVec4};
 
 #[inline(always)]
 pub fn lorentz_factor(beta_squared: f64) -> f64 {
@@ -47,6 +47,7 @@ pub fn lorentz_transform_matrix(beta: DVec3) -> DMat4 {
 }
 
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SpacetimeEvent {
     pub pos: DVec3,
     pub time: f64,
@@ -71,18 +72,9 @@ impl SpacetimeEvent {
     }
 
     pub fn to_reference_frame(self, v: DVec3, c: f64) -> Self {
-        let beta = v / c;
-
-        let matrix = lorentz_transform_matrix(beta);
-
-        let vec = DVec4::new(self.time * c, self.pos.x, self.pos.y, self.pos.z);
-
-        let transformed = matrix * vec;
-
-        Self {
-            pos: DVec3::new(transformed.y, transformed.z, transformed.w),
-            time: transformed.x / c,
-        }
+        FourPosition::from_event(self, c)
+            .to_reference_frame(v, c)
+            .to_event(c)
     }
 
     /// Calculate the squared spacetime interval between two events.
@@ -93,27 +85,104 @@ impl SpacetimeEvent {
         (c * delta_time).powi(2) - delta_pos.length_squared()
     }
 
+    /// Classify separation between two events using [`IntervalTolerance::DEFAULT`].
     pub fn get_separation(self, other: Self, c: f64) -> SpacetimeSeparation {
-        let interval = self.spacetime_interval_squared(other, c);
+        self.get_separation_with_tolerance(other, c, IntervalTolerance::DEFAULT)
+    }
 
-        if interval >= 0.0 {
-            SpacetimeSeparation::Timelike
-        } else {
-            SpacetimeSeparation::Spacelike
-        }
+    pub fn get_separation_with_tolerance(
+        self,
+        other: Self,
+        c: f64,
+        tolerance: IntervalTolerance,
+    ) -> SpacetimeSeparation {
+        let delta_pos = other.pos - self.pos;
+        let delta_time = other.time - self.time;
+
+        let time_squared = (c * delta_time).powi(2);
+        let pos_squared = delta_pos.length_squared();
+
+        tolerance.classify(time_squared - pos_squared, time_squared + pos_squared)
     }
 
     pub fn light_cone(self, c: f64) -> LightCone {
         LightCone::new(self, c)
     }
+
+    /// Check if a signal sent from this event can reach the `other` event, i.e. `other` is in the
+    /// causal future (inside or on the future light cone) of this event.
+    pub fn can_influence(self, other: Self, c: f64) -> bool {
+        self.light_cone(c).future_contains(other)
+    }
+
+    /// Check if this event is in the causal future of the `other` event.
+    pub fn is_in_causal_future(self, other: Self, c: f64) -> bool {
+        other.can_influence(self, c)
+    }
+
+    /// Check if this event is in the causal past of the `other` event.
+    pub fn is_in_causal_past(self, other: Self, c: f64) -> bool {
+        self.can_influence(other, c)
+    }
+
+    /// Check if this event is inside the causal diamond of `past` and `future` events, i.e. it
+    /// can be influenced by `past` and can influence `future`.
+    pub fn is_in_causal_diamond(self, past: Self, future: Self, c: f64) -> bool {
+        past.can_influence(self, c) && self.can_influence(future, c)
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum SpacetimeSeparation {
     Spacelike,
+    /// Events are connected by a light ray (the interval is zero within the tolerance).
+    Lightlike,
     Timelike,
 }
 
+/// Tolerance of classifying the squared spacetime interval as lightlike, see
+/// [`SpacetimeSeparation::Lightlike`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IntervalTolerance {
+    /// Only the exactly zero interval is lightlike.
+    Exact,
+    /// Interval is lightlike if `|interval^2| <= tolerance`.
+    Absolute(f64),
+    /// Interval is lightlike if `|interval^2| <= tolerance * ((c * dt)^2 + |dx|^2)`, so the
+    /// tolerance scales with the distance between events.
+    Relative(f64),
+}
+
+impl IntervalTolerance {
+    pub const DEFAULT: Self = Self::Relative(1e-10);
+
+    /// Classify the squared interval `interval_squared = (c * dt)^2 - |dx|^2` of the separation
+    /// with `scale_squared = (c * dt)^2 + |dx|^2`.
+    pub fn classify(self, interval_squared: f64, scale_squared: f64) -> SpacetimeSeparation {
+        let tolerance = match self {
+            Self::Exact => 0.0,
+            Self::Absolute(tolerance) => tolerance,
+            Self::Relative(tolerance) => tolerance * scale_squared,
+        };
+
+        if interval_squared.abs() <= tolerance {
+            SpacetimeSeparation::Lightlike
+        } else if interval_squared > 0.0 {
+            SpacetimeSeparation::Timelike
+        } else {
+            SpacetimeSeparation::Spacelike
+        }
+    }
+}
+
+impl Default for IntervalTolerance {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
 impl std::ops::Sub for SpacetimeEvent {
     type Output = SpacetimeEvent;
 
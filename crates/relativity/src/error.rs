@@ -0,0 +1,115 @@
+use crate::*;
+use glam::DVec3;
+
+/// Violation of world line invariants.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WorldLineError {
+    /// World line must have at least one event.
+    Empty,
+    /// Velocity of the event is not less than the speed of light.
+    SuperluminalVelocity { velocity: DVec3, c: f64 },
+    /// Event does not happen after the previous event of the world line.
+    NotCausallyOrdered { prev_time: f64, time: f64 },
+    /// Event is spacelike or lightlike separated from the previous event of the world line.
+    SpacelikeSegment { interval_squared: f64 },
+    /// Event does not lie on the last segment of the world line continued to the event's time.
+    OffWorldLine { pos: DVec3, expected_pos: DVec3 },
+    /// World line has already ended, nothing can be changed after its end.
+    Ended { end_time: f64 },
+}
+
+impl std::fmt::Display for WorldLineError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Empty => write!(f, "world line has no events"),
+            Self::SuperluminalVelocity { velocity, c } => write!(
+                f,
+                "velocity {velocity} (|v| = {}) is not less than the speed of light {c}",
+                velocity.length()
+            ),
+            Self::NotCausallyOrdered { prev_time, time } => write!(
+                f,
+                "event at time {time} does not happen after the previous event at time {prev_time}"
+            ),
+            Self::SpacelikeSegment { interval_squared } => write!(
+                f,
+                "event is not timelike separated from the previous event (interval^2 = \
+                 {interval_squared})"
+            ),
+            Self::OffWorldLine { pos, expected_pos } => write!(
+                f,
+                "event at {pos} does not lie on the last segment of the world line, expected \
+                 {expected_pos}"
+            ),
+            Self::Ended { end_time } => {
+                write!(f, "world line has already ended at time {end_time}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for WorldLineError {}
+
+/// Failure of [`WorldLine::get_visible_event`] or [`WorldLine::get_current_event`].
+///
+/// [`DegenerateVelocity`](Self::DegenerateVelocity) and
+/// [`NumericalFailure`](Self::NumericalFailure) are also logged with the `log` crate at debug
+/// level together with the observer's event. They tend to happen for many objects at once every
+/// frame, so reporting them to the user is left to the caller.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum WorldLineQueryError {
+    /// World line has not reached the observer yet: the light from its start has not arrived (for
+    /// visible events) or it starts later in the observer's reference frame (for simultaneous
+    /// events).
+    NotYetVisible,
+    /// World line has ended before the observer's event: the light from its end has already
+    /// arrived (for visible events) or it ends earlier in the observer's reference frame (for
+    /// simultaneous events).
+    AlreadyEnded {
+        /// End of the world line in main reference frame.
+        end_coord: SpacetimeEvent,
+    },
+    /// Velocity of the object or of the observer is not less than the speed of light, so the
+    /// Lorentz factor is infinite or undefined.
+    DegenerateVelocity { velocity: DVec3, c: f64 },
+    /// Intersection with the observer's past light cone (for visible events) or simultaneity
+    /// hyperplane (for simultaneous events) was not found because of floating point errors.
+    NumericalFailure {
+        /// Position of the world line event the search started from in observer's reference
+        /// frame relative to the observer's event.
+        relative_coord: SpacetimeEvent,
+        /// Velocity of the object in reference frame of the observer.
+        relative_velocity: DVec3,
+    },
+}
+
+impl std::fmt::Display for WorldLineQueryError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotYetVisible => write!(f, "world line is not visible yet"),
+            Self::AlreadyEnded { end_coord } => write!(
+                f,
+                "world line has already ended at {} (t = {})",
+                end_coord.pos, end_coord.time
+            ),
+            Self::DegenerateVelocity { velocity, c } => write!(
+                f,
+                "degenerate velocity {velocity} (|v| = {}) for the speed of light {c}",
+                velocity.length()
+            ),
+            Self::NumericalFailure {
+                relative_coord,
+                relative_velocity,
+            } => write!(
+                f,
+                "no intersection with the observer's light cone or simultaneity hyperplane found \
+                 for the event at {} (t = {}) moving with {relative_velocity}",
+                relative_coord.pos, relative_coord.time
+            ),
+        }
+    }
+}
+
+impl std::error::Error for WorldLineQueryError {}
@@ -0,0 +1,283 @@
+use crate::*;
+use glam::{DMat4, DVec3, DVec4};
+
+/// Common operations of four-vectors with `(+, -, -, -)` metric signature.
+///
+/// Time component of every four-vector is stored in spatial units (multiplied by the speed of
+/// light) so all components are transformed by the same [`lorentz_transform_matrix`].
+pub trait FourVector: Copy {
+    fn from_dvec4(vec: DVec4) -> Self;
+
+    /// Components in `(t, x, y, z)` order.
+    fn to_dvec4(self) -> DVec4;
+
+    /// Minkowski inner product.
+    fn minkowski_dot(self, other: Self) -> f64 {
+        let a = self.to_dvec4();
+        let b = other.to_dvec4();
+
+        a.x * b.x - a.y * b.y - a.z * b.z - a.w * b.w
+    }
+
+    /// Squared Minkowski norm, positive for timelike vectors.
+    fn norm_squared(self) -> f64 {
+        self.minkowski_dot(self)
+    }
+
+    /// Minkowski norm, `sqrt(|norm_squared|)`.
+    fn norm(self) -> f64 {
+        self.norm_squared().abs().sqrt()
+    }
+
+    /// Classify the vector using [`IntervalTolerance::DEFAULT`].
+    fn separation(self) -> SpacetimeSeparation {
+        let vec = self.to_dvec4();
+
+        IntervalTolerance::DEFAULT.classify(self.norm_squared(), vec.length_squared())
+    }
+
+    /// Transform the vector to the reference frame moving with velocity `v`.
+    fn to_reference_frame(self, v: DVec3, c: f64) -> Self {
+        self.lorentz_transform(lorentz_transform_matrix(v / c))
+    }
+
+    /// Transform the vector to the reference frame moving with `rapidity`.
+    ///
+    /// Unlike the matrix of the boost, stays precise for large rapidities whose `cosh` and `sinh`
+    /// are equal in `f64`: the light cone components are scaled by `e^rapidity` and
+    /// `e^-rapidity` instead.
+    fn to_rapidity_frame(self, rapidity: Rapidity) -> Self {
+        let vec = self.to_dvec4();
+        let spatial = DVec3::new(vec.y, vec.z, vec.w);
+
+        let direction = rapidity.direction;
+        let t = vec.x;
+        let par = direction.dot(spatial);
+
+        let (new_t, new_par) = if rapidity.magnitude < 1.0 {
+            let sinh = rapidity.magnitude.sinh();
+            let cosh_minus_one = 2.0 * (rapidity.magnitude * 0.5).sinh().powi(2);
+
+            (
+                t + cosh_minus_one * t - sinh * par,
+                par + cosh_minus_one * par - sinh * t,
+            )
+        } else {
+            let minus = rapidity.magnitude.exp() * (t - par);
+            let plus = (-rapidity.magnitude).exp() * (t + par);
+
+            (0.5 * (plus + minus), 0.5 * (plus - minus))
+        };
+
+        let new_spatial = spatial + direction * (new_par - par);
+
+        Self::from_dvec4(DVec4::new(
+            new_t,
+            new_spatial.x,
+            new_spatial.y,
+            new_spatial.z,
+        ))
+    }
+
+    fn lorentz_transform(self, matrix: DMat4) -> Self {
+        Self::from_dvec4(matrix * self.to_dvec4())
+    }
+}
+
+/// Four-position `(c * t, x, y, z)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FourPosition {
+    /// Time multiplied by the speed of light.
+    pub t: f64,
+    pub spatial: DVec3,
+}
+
+impl FourPosition {
+    pub fn from_event(event: SpacetimeEvent, c: f64) -> Self {
+        Self {
+            t: event.time * c,
+            spatial: event.pos,
+        }
+    }
+
+    pub fn to_event(self, c: f64) -> SpacetimeEvent {
+        SpacetimeEvent {
+            pos: self.spatial,
+            time: self.t / c,
+        }
+    }
+}
+
+/// Four-velocity `(gamma * c, gamma * v)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FourVelocity {
+    /// Lorentz factor multiplied by the speed of light.
+    pub t: f64,
+    /// Proper velocity, `gamma * v`.
+    pub spatial: DVec3,
+}
+
+impl FourVelocity {
+    pub fn from_velocity(v: DVec3, c: f64) -> Self {
+        let gamma = lorentz_factor_from_vel(v, c);
+
+        Self {
+            t: gamma * c,
+            spatial: v * gamma,
+        }
+    }
+
+    pub fn from_rapidity(rapidity: Rapidity, c: f64) -> Self {
+        Self {
+            t: rapidity.gamma() * c,
+            spatial: rapidity.proper_velocity(c),
+        }
+    }
+
+    #[inline(always)]
+    pub fn gamma(self, c: f64) -> f64 {
+        self.t / c
+    }
+
+    /// Three-velocity.
+    pub fn velocity(self, c: f64) -> DVec3 {
+        self.spatial * c / self.t
+    }
+
+    /// Rapidity computed from the proper velocity, so it doesn't saturate near the speed of light.
+    pub fn rapidity(self, c: f64) -> Rapidity {
+        Rapidity::new(self.spatial, (self.spatial.length() / c).asinh())
+    }
+}
+
+/// Four-momentum `(E / c, p)`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FourMomentum {
+    /// Energy divided by the speed of light.
+    pub t: f64,
+    /// Relativistic momentum.
+    pub spatial: DVec3,
+}
+
+impl FourMomentum {
+    pub fn from_four_velocity(mass: f64, four_velocity: FourVelocity) -> Self {
+        Self::from_dvec4(four_velocity.to_dvec4() * mass)
+    }
+
+    pub fn from_velocity(mass: f64, v: DVec3, c: f64) -> Self {
+        Self::from_four_velocity(mass, FourVelocity::from_velocity(v, c))
+    }
+
+    #[inline(always)]
+    pub fn energy(self, c: f64) -> f64 {
+        self.t * c
+    }
+
+    /// Invariant (rest) mass.
+    #[inline(always)]
+    pub fn mass(self, c: f64) -> f64 {
+        self.norm() / c
+    }
+
+    /// Three-velocity.
+    pub fn velocity(self, c: f64) -> DVec3 {
+        self.spatial * c / self.t
+    }
+
+    /// Rapidity computed from the proper velocity, so it doesn't saturate near the speed of light.
+    pub fn rapidity(self, c: f64) -> Rapidity {
+        Rapidity::new(self.spatial, (self.spatial.length() / c).asinh())
+    }
+}
+
+impl WorldLineEvent {
+    pub fn four_position(&self, c: f64) -> FourPosition {
+        FourPosition::from_event(self.coord, c)
+    }
+
+    pub fn four_velocity(&self, c: f64) -> FourVelocity {
+        FourVelocity::from_rapidity(self.rapidity, c)
+    }
+
+    pub fn four_momentum(&self, mass: f64, c: f64) -> FourMomentum {
+        FourMomentum::from_four_velocity(mass, self.four_velocity(c))
+    }
+}
+
+macro_rules! impl_four_vector {
+    ($type:ident) => {
+        impl FourVector for $type {
+            #[inline(always)]
+            fn from_dvec4(vec: DVec4) -> Self {
+                Self {
+                    t: vec.x,
+                    spatial: DVec3::new(vec.y, vec.z, vec.w),
+                }
+            }
+
+            #[inline(always)]
+            fn to_dvec4(self) -> DVec4 {
+                DVec4::new(self.t, self.spatial.x, self.spatial.y, self.spatial.z)
+            }
+        }
+
+        impl $type {
+            pub const ZERO: Self = Self {
+                t: 0.0,
+                spatial: DVec3::ZERO,
+            };
+
+            #[inline(always)]
+            pub fn new(t: f64, spatial: DVec3) -> Self {
+                Self { t, spatial }
+            }
+        }
+
+        impl std::ops::Add for $type {
+            type Output = $type;
+
+            fn add(self, rhs: Self) -> Self::Output {
+                Self::from_dvec4(self.to_dvec4() + rhs.to_dvec4())
+            }
+        }
+
+        impl std::ops::Sub for $type {
+            type Output = $type;
+
+            fn sub(self, rhs: Self) -> Self::Output {
+                Self::from_dvec4(self.to_dvec4() - rhs.to_dvec4())
+            }
+        }
+
+        impl std::ops::Neg for $type {
+            type Output = $type;
+
+            fn neg(self) -> Self::Output {
+                Self::from_dvec4(-self.to_dvec4())
+            }
+        }
+
+        impl std::ops::Mul<f64> for $type {
+            type Output = $type;
+
+            fn mul(self, rhs: f64) -> Self::Output {
+                Self::from_dvec4(self.to_dvec4() * rhs)
+            }
+        }
+
+        impl std::ops::Div<f64> for $type {
+            type Output = $type;
+
+            fn div(self, rhs: f64) -> Self::Output {
+                Self::from_dvec4(self.to_dvec4() / rhs)
+            }
+        }
+    };
+}
+
+impl_four_vector!(FourPosition);
+impl_four_vector!(FourVelocity);
+impl_four_vector!(FourMomentum);
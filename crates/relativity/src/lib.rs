@@ -1,11 +1,34 @@
 mod coordinate;
+mod error;
+mod four_vector;
 mod light_cone;
+mod light_signal;
+mod lorentz_transform;
+mod motion;
+mod optics;
+mod rapidity;
+mod reference_frame;
 mod velocity;
 mod world_line;
 
 #[allow(unused_imports)]
 pub(crate) use crate as relativity;
+#[cfg(feature = "bevy")]
+pub use bevy_math;
 pub use coordinate::*;
+pub use error::*;
+pub use four_vector::*;
+pub use glam;
 pub use light_cone::*;
+pub use light_signal::*;
+pub use lorentz_transform::*;
+pub use motion::*;
+pub use optics::*;
+pub use rapidity::*;
+pub use reference_frame::*;
 pub use velocity::*;
 pub use world_line::*;
+
+// math types of the crate are the same as bevy ones as long as both use the same glam version
+#[cfg(feature = "bevy")]
+const _: fn(bevy_math::DVec3) -> glam::DVec3 = |v| v;
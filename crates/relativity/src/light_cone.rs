@@ -1,9 +1,13 @@
 use crate::*;
 
+/// Light cone of the `origin` event. Events on the cone (lightlike separated within the
+/// `tolerance`) are contained by it.
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LightCone {
     pub origin: SpacetimeEvent,
     pub light_speed: f64,
+    pub tolerance: IntervalTolerance,
 }
 
 impl LightCone {
@@ -11,20 +15,36 @@ impl LightCone {
         Self {
             origin,
             light_speed,
+            tolerance: IntervalTolerance::DEFAULT,
         }
     }
 
+    pub fn with_tolerance(mut self, tolerance: IntervalTolerance) -> Self {
+        self.tolerance = tolerance;
+
+        self
+    }
+
     pub fn future_contains(self, event: SpacetimeEvent) -> bool {
-        event.time > self.origin.time
-            && event.get_separation(self.origin, self.light_speed) == SpacetimeSeparation::Timelike
+        event.time > self.origin.time && self.contains(event)
     }
 
     pub fn past_contains(self, event: SpacetimeEvent) -> bool {
-        event.time < self.origin.time
-            && event.get_separation(self.origin, self.light_speed) == SpacetimeSeparation::Timelike
+        event.time < self.origin.time && self.contains(event)
     }
 
+    /// Check if the event is inside or on the cone.
     pub fn contains(self, event: SpacetimeEvent) -> bool {
-        event.get_separation(self.origin, self.light_speed) == SpacetimeSeparation::Timelike
+        self.separation(event) != SpacetimeSeparation::Spacelike
+    }
+
+    /// Check if the event is on the cone, i.e. it is connected with the origin by a light ray.
+    pub fn surface_contains(self, event: SpacetimeEvent) -> bool {
+        self.separation(event) == SpacetimeSeparation::Lightlike
+    }
+
+    fn separation(self, event: SpacetimeEvent) -> SpacetimeSeparation {
+        self.origin
+            .get_separation_with_tolerance(event, self.light_speed, self.tolerance)
     }
 }
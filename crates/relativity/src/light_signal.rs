@@ -0,0 +1,81 @@
+use crate::*;
+
+/// Light signal sent from one world line to another.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LightSignal {
+    /// State of the sender at the emission.
+    pub emission: WorldLineEvent,
+    /// State of the receiver at the reception.
+    pub reception: WorldLineEvent,
+}
+
+/// Light signal sent by the observer, reflected by the target and received back by the observer.
+#[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RadarEcho {
+    /// State of the observer at the emission.
+    pub emission: WorldLineEvent,
+    /// State of the target at the reflection.
+    pub reflection: WorldLineEvent,
+    /// State of the observer at the reception of the echo.
+    pub reception: WorldLineEvent,
+}
+
+impl RadarEcho {
+    /// Round trip duration of the signal measured by the observer's clock.
+    pub fn round_trip_time(&self) -> f64 {
+        self.reception.object_proper_time - self.emission.object_proper_time
+    }
+
+    /// Radar distance to the reflection event, `c * round_trip_time / 2`.
+    pub fn distance(&self, c: f64) -> f64 {
+        c * self.round_trip_time() * 0.5
+    }
+
+    /// Radar time of the reflection event, i.e. the observer's proper time in the middle of the
+    /// round trip.
+    pub fn time(&self) -> f64 {
+        (self.emission.object_proper_time + self.reception.object_proper_time) * 0.5
+    }
+}
+
+impl WorldLine {
+    /// Send light signal when the clock of the object reads `emission_proper_time` and find where
+    /// it is received by the `target` world line.
+    ///
+    /// Returns `None` if the object doesn't exist at `emission_proper_time` or the signal never
+    /// reaches the target, see [`WorldLine::receive_light_signal`].
+    pub fn send_light_signal(
+        &self,
+        emission_proper_time: f64,
+        target: &WorldLine,
+        c: f64,
+    ) -> Option<LightSignal> {
+        let emission = self.event_at_proper_time(emission_proper_time, c)?;
+        let reception = target.receive_light_signal(emission.coord, c)?;
+
+        Some(LightSignal {
+            emission,
+            reception,
+        })
+    }
+
+    /// Send light signal to the `target` when the clock of the object reads
+    /// `emission_proper_time` and receive its reflection back.
+    pub fn radar_ranging(
+        &self,
+        emission_proper_time: f64,
+        target: &WorldLine,
+        c: f64,
+    ) -> Option<RadarEcho> {
+        let signal = self.send_light_signal(emission_proper_time, target, c)?;
+        let reception = self.receive_light_signal(signal.reception.coord, c)?;
+
+        Some(RadarEcho {
+            emission: signal.emission,
+            reflection: signal.reception,
+            reception,
+        })
+    }
+}
@@ -0,0 +1,177 @@
+use crate::*;
+use glam::{DMat3, DMat4, DQuat, DVec3, DVec4};
+
+/// Proper orthochronous Lorentz transformation (boost combined with rotation) of coordinates from
+/// one reference frame to another.
+///
+/// Matrix acts on `(c * t, x, y, z)` components, see [`FourVector`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LorentzTransform {
+    pub matrix: DMat4,
+}
+
+impl LorentzTransform {
+    pub const IDENTITY: Self = Self {
+        matrix: DMat4::IDENTITY,
+    };
+
+    /// Pure boost to the reference frame moving with velocity `v`.
+    pub fn from_boost(v: DVec3, c: f64) -> Self {
+        Self {
+            matrix: lorentz_transform_matrix(v / c),
+        }
+    }
+
+    /// Pure boost to the reference frame moving with rapidity `rapidity`.
+    ///
+    /// Built from hyperbolic functions of the rapidity, so it stays exact when the velocity
+    /// rounds to the speed of light.
+    pub fn from_rapidity(rapidity: Rapidity) -> Self {
+        let n = rapidity.direction;
+        let sinh = rapidity.magnitude.sinh();
+        let cosh_minus_one = 2.0 * (rapidity.magnitude * 0.5).sinh().powi(2);
+
+        let column = |n_i: f64, axis: DVec3| {
+            let spatial = axis + n * (cosh_minus_one * n_i);
+
+            DVec4::new(-sinh * n_i, spatial.x, spatial.y, spatial.z)
+        };
+
+        Self {
+            matrix: DMat4::from_cols(
+                DVec4::new(1.0 + cosh_minus_one, -sinh * n.x, -sinh * n.y, -sinh * n.z),
+                column(n.x, DVec3::X),
+                column(n.y, DVec3::Y),
+                column(n.z, DVec3::Z),
+            ),
+        }
+    }
+
+    /// Pure rotation of spatial coordinates, `pos' = rotation * pos`.
+    pub fn from_rotation(rotation: DQuat) -> Self {
+        let rotation = DMat3::from_quat(rotation);
+
+        Self {
+            matrix: DMat4::from_cols(
+                DVec4::X,
+                spatial_column(rotation.x_axis),
+                spatial_column(rotation.y_axis),
+                spatial_column(rotation.z_axis),
+            ),
+        }
+    }
+
+    /// Boost to the reference frame moving with velocity `v` followed by rotation of its axes.
+    pub fn from_boost_rotation(v: DVec3, rotation: DQuat, c: f64) -> Self {
+        Self::from_boost(v, c).then(Self::from_rotation(rotation))
+    }
+
+    /// Transformation which applies `self` first and `next` after it.
+    pub fn then(self, next: Self) -> Self {
+        Self {
+            matrix: next.matrix * self.matrix,
+        }
+    }
+
+    /// Inverse transformation, `eta * matrix^T * eta`.
+    pub fn inverse(self) -> Self {
+        let metric = DMat4::from_diagonal(DVec4::new(1.0, -1.0, -1.0, -1.0));
+
+        Self {
+            matrix: metric * self.matrix.transpose() * metric,
+        }
+    }
+
+    pub fn transform<V: FourVector>(self, vec: V) -> V {
+        vec.lorentz_transform(self.matrix)
+    }
+
+    pub fn transform_event(self, event: SpacetimeEvent, c: f64) -> SpacetimeEvent {
+        self.transform(FourPosition::from_event(event, c))
+            .to_event(c)
+    }
+
+    pub fn transform_velocity(self, v: DVec3, c: f64) -> DVec3 {
+        self.transform(FourVelocity::from_velocity(v, c))
+            .velocity(c)
+    }
+
+    /// Velocity of the target reference frame relative to the source reference frame (in the
+    /// source reference frame).
+    pub fn boost_velocity(self, c: f64) -> DVec3 {
+        // origin of the target frame is at rest in it
+        self.inverse()
+            .transform(FourVelocity::new(c, DVec3::ZERO))
+            .velocity(c)
+    }
+
+    /// Rapidity of the target reference frame relative to the source reference frame (in the
+    /// source reference frame).
+    pub fn boost_rapidity(self, c: f64) -> Rapidity {
+        self.inverse()
+            .transform(FourVelocity::new(c, DVec3::ZERO))
+            .rapidity(c)
+    }
+
+    /// Split the transformation into the boost velocity and the rotation, so that
+    /// `self == LorentzTransform::from_boost_rotation(velocity, rotation, c)`.
+    pub fn decompose(self, c: f64) -> (DVec3, DQuat) {
+        let (rapidity, rotation) = self.decompose_rapidity(c);
+
+        (rapidity.to_velocity(c), rotation)
+    }
+
+    /// Same as [`LorentzTransform::decompose`] but return the boost rapidity, which stays precise
+    /// near the speed of light.
+    pub fn decompose_rapidity(self, c: f64) -> (Rapidity, DQuat) {
+        let rapidity = self.boost_rapidity(c);
+        let rotation = Self::from_rapidity(-rapidity).then(self);
+
+        (rapidity, rotation.spatial_rotation())
+    }
+
+    /// Wigner rotation of the transformation, i.e. rotation part of
+    /// [`LorentzTransform::decompose`].
+    pub fn rotation(self, c: f64) -> DQuat {
+        self.decompose(c).1
+    }
+
+    fn spatial_rotation(self) -> DQuat {
+        let m = self.matrix;
+
+        DQuat::from_mat3(&DMat3::from_cols(
+            spatial_part(m.y_axis),
+            spatial_part(m.z_axis),
+            spatial_part(m.w_axis),
+        ))
+        .normalize()
+    }
+}
+
+impl std::ops::Mul for LorentzTransform {
+    type Output = LorentzTransform;
+
+    /// `a * b` applies `b` first, same as matrix multiplication.
+    fn mul(self, rhs: Self) -> Self::Output {
+        rhs.then(self)
+    }
+}
+
+#[inline(always)]
+fn spatial_column(v: DVec3) -> DVec4 {
+    DVec4::new(0.0, v.x, v.y, v.z)
+}
+
+#[inline(always)]
+fn spatial_part(v: DVec4) -> DVec3 {
+    DVec3::new(v.y, v.z, v.w)
+}
+
+/// Thomas-Wigner rotation produced by boosting to the frame moving with `v1` and then to the frame
+/// moving with `v2` relative to the first one.
+pub fn wigner_rotation(v1: DVec3, v2: DVec3, c: f64) -> DQuat {
+    LorentzTransform::from_boost(v1, c)
+        .then(LorentzTransform::from_boost(v2, c))
+        .rotation(c)
+}
@@ -0,0 +1,87 @@
+use crate::*;
+use glam::DVec3;
+
+/// State of the object moving with piecewise constant proper acceleration, e.g. the observer
+/// controlled by the user.
+///
+/// Velocity is stored as [`Rapidity`], so the object can accelerate indefinitely.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MotionState {
+    /// Space time coordinate of the object in main reference frame.
+    pub coord: SpacetimeEvent,
+    /// Rapidity of the object in main reference frame.
+    pub rapidity: Rapidity,
+    /// Proper time of the object.
+    pub proper_time: f64,
+}
+
+impl MotionState {
+    /// Object at rest at the event `coord` with zero proper time.
+    pub fn new(coord: SpacetimeEvent) -> Self {
+        Self {
+            coord,
+            rapidity: Rapidity::ZERO,
+            proper_time: 0.0,
+        }
+    }
+
+    #[inline(always)]
+    pub fn velocity(&self, c: f64) -> DVec3 {
+        self.rapidity.to_velocity(c)
+    }
+
+    /// Get the state of the object after `delta_proper_time` of its proper time passed with
+    /// constant `proper_acceleration`.
+    ///
+    /// Uses the exact hyperbolic motion, so the result is the same as following the
+    /// [`WorldLineEvent`] segment started at this state. Splitting the step into several ones
+    /// gives the same result only if the acceleration is parallel to the velocity, otherwise the
+    /// rest frame of every step is rotated relative to the previous one (Thomas precession), so
+    /// long segments must be advanced from their start.
+    ///
+    /// # Parameters
+    /// - `proper_acceleration`: Acceleration of the object in its rest frame (boosted from main
+    ///   reference frame without rotation), same as [`WorldLineEvent::proper_acceleration`].
+    /// - `delta_proper_time`: Proper time step.
+    /// - `c`: Speed of light.
+    pub fn advance(self, proper_acceleration: DVec3, delta_proper_time: f64, c: f64) -> Self {
+        let acceleration = proper_acceleration.length();
+
+        let (rest_coord, rest_rapidity) = if acceleration == 0.0 {
+            (
+                SpacetimeEvent::ZERO.with_time(delta_proper_time),
+                Rapidity::ZERO,
+            )
+        } else {
+            let direction = proper_acceleration / acceleration;
+            let radius = c * c / acceleration;
+            let rapidity = acceleration * delta_proper_time / c;
+
+            // hyperbolic motion in the rest frame at the start of the step
+            let rest_coord = SpacetimeEvent {
+                pos: direction * (2.0 * radius * (rapidity * 0.5).sinh().powi(2)),
+                time: radius / c * rapidity.sinh(),
+            };
+
+            (rest_coord, Rapidity::new(direction, rapidity))
+        };
+
+        Self {
+            coord: self.coord + self.rapidity.event_from_rest_frame(rest_coord, c),
+            rapidity: self.rapidity.compose(rest_rapidity),
+            proper_time: self.proper_time + delta_proper_time,
+        }
+    }
+
+    /// World line event starting the segment of the given constant `proper_acceleration` at this
+    /// state.
+    pub fn to_world_line_event(self, proper_acceleration: DVec3) -> WorldLineEvent {
+        WorldLineEvent {
+            coord: self.coord,
+            rapidity: self.rapidity,
+            object_proper_time: self.proper_time,
+            proper_acceleration,
+        }
+    }
+}
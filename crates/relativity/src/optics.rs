@@ -0,0 +1,57 @@
+use crate::*;
+use glam::{DVec3, DVec4};
+
+/// Relativistic Doppler factor, ratio of the observed frequency to the emitted one.
+///
+/// # Parameters
+/// - `source_velocity`: Velocity of the source in the observer's reference frame.
+/// - `propagation_direction`: Unit direction of the light from the source to the observer in the
+///   observer's reference frame.
+/// - `c`: Speed of light.
+pub fn doppler_factor(source_velocity: DVec3, propagation_direction: DVec3, c: f64) -> f64 {
+    let beta = source_velocity / c;
+    let gamma = lorentz_factor(beta.length_squared());
+
+    1.0 / (gamma * (1.0 - beta.dot(propagation_direction)))
+}
+
+/// Transform the direction towards the light source seen by the observer at rest into the
+/// direction seen by the observer at the same event moving with velocity `v`.
+pub fn aberrate_direction(direction: DVec3, v: DVec3, c: f64) -> DVec3 {
+    // wave vector of the light propagating opposite to the direction towards the source
+    let wave_vector = DVec4::new(1.0, -direction.x, -direction.y, -direction.z);
+
+    let transformed = lorentz_transform_matrix(v / c) * wave_vector;
+
+    -DVec3::new(transformed.y, transformed.z, transformed.w) / transformed.x
+}
+
+impl VisibleWorldLineEvent {
+    /// Unit direction from the observer towards the visible image of the object.
+    pub fn apparent_direction(&self) -> DVec3 {
+        self.relative_coord.pos.normalize_or_zero()
+    }
+
+    /// Direction towards the object as seen by the observer at the same event, but at rest
+    /// relative to the object.
+    pub fn rest_frame_direction(&self, c: f64) -> DVec3 {
+        aberrate_direction(self.apparent_direction(), self.relative_velocity, c)
+    }
+
+    /// Ratio of the observed frequency to the emitted one, greater than 1 for blueshift.
+    pub fn doppler_factor(&self, c: f64) -> f64 {
+        doppler_factor(self.relative_velocity, -self.apparent_direction(), c)
+    }
+
+    /// Ratio of the apparent angular size of the object to its angular size seen by the observer
+    /// at the same event at rest relative to the object.
+    pub fn angular_size_factor(&self, c: f64) -> f64 {
+        1.0 / self.doppler_factor(c)
+    }
+
+    /// Ratio of the observed bolometric intensity (surface brightness) to the emitted one,
+    /// `doppler_factor^4`.
+    pub fn intensity_factor(&self, c: f64) -> f64 {
+        self.doppler_factor(c).powi(4)
+    }
+}
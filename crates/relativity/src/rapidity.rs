@@ -0,0 +1,189 @@
+use crate::*;
+use glam::DVec3;
+
+/// Rapidity above which hyperbolic functions are computed scaled down to avoid overflow (squared
+/// `sinh` of it must still fit into `f64`).
+const MAX_UNSCALED_RAPIDITY: f64 = 300.0;
+
+/// Rapidity of the object, i.e. hyperbolic angle of the boost from the reference frame to the
+/// object's rest frame.
+///
+/// Unlike velocity, rapidity is additive for collinear boosts and does not saturate near the
+/// speed of light, so it can be accumulated without loss of precision.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Rapidity {
+    /// Unit direction of the motion (zero if the magnitude is zero).
+    pub direction: DVec3,
+    /// Rapidity magnitude, `atanh(|v| / c)`.
+    pub magnitude: f64,
+}
+
+impl Rapidity {
+    pub const ZERO: Self = Self {
+        direction: DVec3::ZERO,
+        magnitude: 0.0,
+    };
+
+    /// Create rapidity from direction (not necessarily normalized) and magnitude.
+    ///
+    /// NaN direction or magnitude (e.g. from superluminal velocity) produce NaN rapidity.
+    pub fn new(direction: DVec3, magnitude: f64) -> Self {
+        if direction.is_nan() || magnitude.is_nan() {
+            return Self {
+                direction: DVec3::NAN,
+                magnitude: f64::NAN,
+            };
+        }
+
+        let direction = direction.normalize_or_zero();
+
+        if direction == DVec3::ZERO || magnitude == 0.0 {
+            return Self::ZERO;
+        }
+
+        if magnitude < 0.0 {
+            return Self {
+                direction: -direction,
+                magnitude: -magnitude,
+            };
+        }
+
+        Self {
+            direction,
+            magnitude,
+        }
+    }
+
+    #[inline(always)]
+    pub fn from_velocity(v: DVec3, c: f64) -> Self {
+        Self::from_beta(v / c)
+    }
+
+    pub fn from_beta(beta: DVec3) -> Self {
+        let beta_len = beta.length();
+
+        Self::new(beta, beta_len.atanh())
+    }
+
+    /// Create rapidity from direction and lorentz factor.
+    pub fn from_gamma(direction: DVec3, gamma: f64) -> Self {
+        Self::new(direction, gamma.acosh())
+    }
+
+    #[inline(always)]
+    pub fn to_velocity(self, c: f64) -> DVec3 {
+        self.beta() * c
+    }
+
+    #[inline(always)]
+    pub fn beta(self) -> DVec3 {
+        self.direction * self.magnitude.tanh()
+    }
+
+    #[inline(always)]
+    pub fn gamma(self) -> f64 {
+        self.magnitude.cosh()
+    }
+
+    /// Spatial part of the four-velocity, `gamma * v`.
+    #[inline(always)]
+    pub fn proper_velocity(self, c: f64) -> DVec3 {
+        self.direction * (self.magnitude.sinh() * c)
+    }
+
+    /// Transform event from the rest frame of the object moving with this rapidity to the
+    /// original reference frame.
+    ///
+    /// Same as `event.to_reference_frame(-self_velocity, c)` but stays precise near the speed of
+    /// light.
+    pub fn event_from_rest_frame(self, event: SpacetimeEvent, c: f64) -> SpacetimeEvent {
+        let sinh = self.magnitude.sinh();
+        let cosh_minus_one = 2.0 * (self.magnitude * 0.5).sinh().powi(2);
+
+        let ct = c * event.time;
+        let pos_par = self.direction.dot(event.pos);
+
+        SpacetimeEvent {
+            pos: event.pos + self.direction * (cosh_minus_one * pos_par + sinh * ct),
+            time: (ct + cosh_minus_one * ct + sinh * pos_par) / c,
+        }
+    }
+
+    /// Compose two boosts: `other` is the rapidity of the object relative to the reference frame
+    /// moving with `self`, the result is the rapidity of the object in the original reference
+    /// frame.
+    ///
+    /// Same as `velocity_to_new_rf(-self_velocity, other_velocity, c)` but stays precise for
+    /// arbitrary large rapidities. Resulting Wigner rotation is ignored.
+    pub fn compose(self, other: Self) -> Self {
+        if self.magnitude == 0.0 {
+            return other;
+        }
+        if other.magnitude == 0.0 {
+            return self;
+        }
+
+        let a = self.magnitude;
+        let b = other.magnitude;
+
+        let (scale_a, scale_b) = if a + b > MAX_UNSCALED_RAPIDITY {
+            (a, b)
+        } else {
+            (0.0, 0.0)
+        };
+
+        let sinh_a = scaled_sinh(a, scale_a);
+        let cosh_a_minus_one = 2.0 * scaled_sinh(a * 0.5, scale_a * 0.5).powi(2);
+        let sinh_b = scaled_sinh(b, scale_b);
+        let cosh_b = scaled_cosh(b, scale_b);
+
+        let cos_ab = self.direction.dot(other.direction);
+
+        // spatial part of the composed four-velocity divided by `e^(scale_a + scale_b)`
+        let spatial = other.direction * (sinh_b * (-scale_a).exp())
+            + self.direction * (cosh_a_minus_one * cos_ab * sinh_b + sinh_a * cosh_b);
+
+        let spatial_len = spatial.length();
+        let scale = scale_a + scale_b;
+
+        let magnitude = if scale == 0.0 {
+            spatial_len.asinh()
+        } else {
+            scale + (spatial_len + (spatial_len.powi(2) + (-2.0 * scale).exp()).sqrt()).ln()
+        };
+
+        Self::new(spatial, magnitude)
+    }
+}
+
+impl std::ops::Neg for Rapidity {
+    type Output = Rapidity;
+
+    fn neg(self) -> Self::Output {
+        Rapidity {
+            direction: -self.direction,
+            magnitude: self.magnitude,
+        }
+    }
+}
+
+/// `sinh(x) * e^-scale`
+#[inline(always)]
+fn scaled_sinh(x: f64, scale: f64) -> f64 {
+    if scale == 0.0 {
+        return x.sinh();
+    }
+
+    0.5 * ((x - scale).exp() - (-x - scale).exp())
+}
+
+/// `cosh(x) * e^-scale`
+#[inline(always)]
+fn scaled_cosh(x: f64, scale: f64) -> f64 {
+    if scale == 0.0 {
+        return x.cosh();
+    }
+
+    0.5 * ((x - scale).exp() + (-x - scale).exp())
+}
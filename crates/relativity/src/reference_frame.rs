@@ -0,0 +1,226 @@
+use crate::*;
+use glam::{DQuat, DVec3};
+
+/// Inertial reference frame described in main reference frame.
+///
+/// Coordinates in the frame are obtained by shifting the main frame coordinates to the `origin`
+/// event, boosting them to the frame moving with `rapidity` and rotating them to the frame's axes.
+///
+/// The boost is computed from the rapidity directly, so the frame stays usable when its velocity
+/// rounds to the speed of light.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReferenceFrame {
+    /// Event of main reference frame which is the origin of this frame.
+    pub origin: SpacetimeEvent,
+    /// Rapidity of the frame in main reference frame.
+    pub rapidity: Rapidity,
+    /// Orientation of the frame's axes relative to the axes of main reference frame boosted to
+    /// `rapidity`, i.e. the frame's coordinates are `orientation.inverse() * pos`.
+    pub orientation: DQuat,
+    /// Speed of light.
+    pub c: f64,
+}
+
+impl ReferenceFrame {
+    /// Main reference frame itself.
+    pub fn main(c: f64) -> Self {
+        Self::new(SpacetimeEvent::ZERO, c)
+    }
+
+    /// Frame at rest relative to main reference frame with the origin at `origin`.
+    pub fn new(origin: SpacetimeEvent, c: f64) -> Self {
+        Self {
+            origin,
+            rapidity: Rapidity::ZERO,
+            orientation: DQuat::IDENTITY,
+            c,
+        }
+    }
+
+    #[inline(always)]
+    pub fn with_velocity(self, velocity: DVec3) -> Self {
+        let c = self.c;
+
+        self.with_rapidity(Rapidity::from_velocity(velocity, c))
+    }
+
+    #[inline(always)]
+    pub fn with_rapidity(mut self, rapidity: Rapidity) -> Self {
+        self.rapidity = rapidity;
+
+        self
+    }
+
+    #[inline(always)]
+    pub fn with_orientation(mut self, orientation: DQuat) -> Self {
+        self.orientation = orientation;
+
+        self
+    }
+
+    /// Velocity of the frame in main reference frame, it rounds to the speed of light for large
+    /// rapidities.
+    #[inline(always)]
+    pub fn velocity(&self) -> DVec3 {
+        self.rapidity.to_velocity(self.c)
+    }
+
+    /// Linear part of the transformation of main reference frame coordinates to this frame.
+    pub fn lorentz_transform(&self) -> LorentzTransform {
+        self.boost()
+            .then(LorentzTransform::from_rotation(self.orientation.inverse()))
+    }
+
+    /// Pure boost from main reference frame to the frame moving with `rapidity`.
+    #[inline(always)]
+    fn boost(&self) -> LorentzTransform {
+        LorentzTransform::from_rapidity(self.rapidity)
+    }
+
+    /// Transform event from main reference frame to this frame.
+    pub fn event_to_frame(&self, event: SpacetimeEvent) -> SpacetimeEvent {
+        let relative = FourPosition::from_event(event - self.origin, self.c)
+            .to_rapidity_frame(self.rapidity)
+            .to_event(self.c);
+
+        SpacetimeEvent {
+            pos: self.orientation.inverse() * relative.pos,
+            time: relative.time,
+        }
+    }
+
+    /// Transform event from this frame to main reference frame.
+    pub fn event_from_frame(&self, event: SpacetimeEvent) -> SpacetimeEvent {
+        let relative = SpacetimeEvent {
+            pos: self.orientation * event.pos,
+            time: event.time,
+        };
+
+        FourPosition::from_event(relative, self.c)
+            .to_rapidity_frame(-self.rapidity)
+            .to_event(self.c)
+            + self.origin
+    }
+
+    /// Transform velocity of the object from main reference frame to this frame.
+    pub fn velocity_to_frame(&self, v: DVec3) -> DVec3 {
+        let velocity = FourVelocity::from_velocity(v, self.c)
+            .to_rapidity_frame(self.rapidity)
+            .velocity(self.c);
+
+        self.orientation.inverse() * velocity
+    }
+
+    /// Transform velocity of the object from this frame to main reference frame.
+    pub fn velocity_from_frame(&self, v: DVec3) -> DVec3 {
+        FourVelocity::from_velocity(self.orientation * v, self.c)
+            .to_rapidity_frame(-self.rapidity)
+            .velocity(self.c)
+    }
+
+    /// Transform rapidity of the object from main reference frame to this frame.
+    ///
+    /// Same as [`ReferenceFrame::velocity_to_frame`] but stays precise when the velocity of the
+    /// object rounds to the speed of light. Resulting Wigner rotation is ignored.
+    pub fn rapidity_to_frame(&self, rapidity: Rapidity) -> Rapidity {
+        let rapidity = FourVelocity::from_rapidity(rapidity, self.c)
+            .to_rapidity_frame(self.rapidity)
+            .rapidity(self.c);
+
+        Rapidity::new(
+            self.orientation.inverse() * rapidity.direction,
+            rapidity.magnitude,
+        )
+    }
+
+    /// Transform rapidity of the object from this frame to main reference frame.
+    pub fn rapidity_from_frame(&self, rapidity: Rapidity) -> Rapidity {
+        let rapidity = Rapidity::new(self.orientation * rapidity.direction, rapidity.magnitude);
+
+        FourVelocity::from_rapidity(rapidity, self.c)
+            .to_rapidity_frame(-self.rapidity)
+            .rapidity(self.c)
+    }
+
+    /// Transform acceleration of the object moving with velocity `v` from main reference frame to
+    /// this frame.
+    ///
+    /// # Parameters
+    /// - `v`: Velocity of the object in main reference frame.
+    /// - `a`: Acceleration of the object in main reference frame.
+    pub fn acceleration_to_frame(&self, v: DVec3, a: DVec3) -> DVec3 {
+        self.orientation.inverse() * acceleration_to_boosted_rf(self.boost(), v, a, self.c)
+    }
+
+    /// Transform acceleration of the object moving with velocity `v` from this frame to main
+    /// reference frame.
+    ///
+    /// # Parameters
+    /// - `v`: Velocity of the object in this frame.
+    /// - `a`: Acceleration of the object in this frame.
+    pub fn acceleration_from_frame(&self, v: DVec3, a: DVec3) -> DVec3 {
+        acceleration_to_boosted_rf(
+            self.boost().inverse(),
+            self.orientation * v,
+            self.orientation * a,
+            self.c,
+        )
+    }
+
+    /// Transform force `dp/dt` acting on the object moving with velocity `v` from main reference
+    /// frame to this frame, see [`force_to_new_rf`].
+    pub fn force_to_frame(&self, v: DVec3, f: DVec3) -> DVec3 {
+        self.orientation.inverse() * force_to_boosted_rf(self.boost(), v, f, self.c)
+    }
+
+    /// Transform force `dp/dt` acting on the object moving with velocity `v` from this frame to
+    /// main reference frame, see [`force_to_new_rf`].
+    pub fn force_from_frame(&self, v: DVec3, f: DVec3) -> DVec3 {
+        force_to_boosted_rf(
+            self.boost().inverse(),
+            self.orientation * v,
+            self.orientation * f,
+            self.c,
+        )
+    }
+
+    /// Frame described by `inner` in this frame's coordinates, described in main reference frame.
+    ///
+    /// Composition of non-collinear boosts produces the Wigner rotation, so orientation of the
+    /// result differs from the composition of orientations, see [`wigner_rotation`].
+    pub fn compose(&self, inner: &Self) -> Self {
+        let (rapidity, rotation) = self
+            .lorentz_transform()
+            .then(inner.lorentz_transform())
+            .decompose_rapidity(self.c);
+
+        Self {
+            origin: self.event_from_frame(inner.origin),
+            rapidity,
+            orientation: rotation.inverse(),
+            c: self.c,
+        }
+    }
+
+    /// Main reference frame described in this frame's coordinates, so that
+    /// `frame.compose(&frame.inverse())` is main reference frame.
+    pub fn inverse(&self) -> Self {
+        let (rapidity, rotation) = self
+            .lorentz_transform()
+            .inverse()
+            .decompose_rapidity(self.c);
+
+        Self {
+            origin: self.event_to_frame(SpacetimeEvent::ZERO),
+            rapidity,
+            orientation: rotation.inverse(),
+            c: self.c,
+        }
+    }
+
+    /// This frame described in the coordinates of `other` frame.
+    pub fn relative_to(&self, other: &Self) -> Self {
+        other.inverse().compose(self)
+    }
+}
@@ -1,5 +1,5 @@
-use crate::lorentz_factor_from_vel;
-use bevy::math::DVec3;
+use crate::{lorentz_factor_from_vel, lorentz_transform_matrix, LorentzTransform};
+use glam::{DVec3, DVec4};
 
 const EPSILON: f64 = 1e-10;
 
@@ -31,3 +31,104 @@ pub fn velocity_to_new_rf(rf_delta_v: DVec3, v: DVec3, c: f64) -> DVec3 {
 
     new_v_par + new_v_perp
 }
+
+/// Transform acceleration from old reference frame(rf) to new reference frame.
+///
+/// # Parameters
+/// - `rf_delta_v`: Velocity change of new reference frame relative to old reference frame (in old
+///   reference frame).
+/// - `v`: Velocity of the object in old reference frame.
+/// - `a`: Acceleration of the object in old reference frame.
+///
+/// # Returns
+/// Acceleration of the object in new reference frame.
+pub fn acceleration_to_new_rf(rf_delta_v: DVec3, v: DVec3, a: DVec3, c: f64) -> DVec3 {
+    acceleration_to_boosted_rf(LorentzTransform::from_boost(rf_delta_v, c), v, a, c)
+}
+
+/// Same as [`acceleration_to_new_rf`] with the new reference frame given by the `boost` to it.
+pub(crate) fn acceleration_to_boosted_rf(
+    boost: LorentzTransform,
+    v: DVec3,
+    a: DVec3,
+    c: f64,
+) -> DVec3 {
+    let transformed = boost.matrix * four_acceleration(v, a, c);
+
+    acceleration_from_four_acceleration(boost.transform_velocity(v, c), transformed, c)
+}
+
+/// Transform force `dp/dt` acting on the object with constant rest mass from old reference frame
+/// to new reference frame.
+///
+/// # Parameters
+/// - `rf_delta_v`: Velocity change of new reference frame relative to old reference frame (in old
+///   reference frame).
+/// - `v`: Velocity of the object in old reference frame.
+/// - `f`: Force acting on the object in old reference frame.
+///
+/// # Returns
+/// Force acting on the object in new reference frame.
+pub fn force_to_new_rf(rf_delta_v: DVec3, v: DVec3, f: DVec3, c: f64) -> DVec3 {
+    force_to_boosted_rf(LorentzTransform::from_boost(rf_delta_v, c), v, f, c)
+}
+
+/// Same as [`force_to_new_rf`] with the new reference frame given by the `boost` to it.
+pub(crate) fn force_to_boosted_rf(boost: LorentzTransform, v: DVec3, f: DVec3, c: f64) -> DVec3 {
+    let gamma = lorentz_factor_from_vel(v, c);
+
+    // four-force `gamma * (f . v / c, f)`, time component is the power of the force
+    let four_force = DVec4::new(gamma * f.dot(v) / c, gamma * f.x, gamma * f.y, gamma * f.z);
+    let transformed = boost.matrix * four_force;
+
+    let new_gamma = lorentz_factor_from_vel(boost.transform_velocity(v, c), c);
+
+    DVec3::new(transformed.y, transformed.z, transformed.w) / new_gamma
+}
+
+/// Proper acceleration of the object, i.e. its acceleration in the instantaneous rest frame
+/// (boosted from the current reference frame without rotation).
+///
+/// # Parameters
+/// - `v`: Velocity of the object.
+/// - `a`: Coordinate acceleration of the object.
+pub fn proper_acceleration(v: DVec3, a: DVec3, c: f64) -> DVec3 {
+    let rest = lorentz_transform_matrix(v / c) * four_acceleration(v, a, c);
+
+    // velocity in the rest frame is zero, so the time component vanishes
+    DVec3::new(rest.y, rest.z, rest.w)
+}
+
+/// Coordinate acceleration of the object moving with velocity `v` with the given proper
+/// acceleration, inverse of [`proper_acceleration`].
+pub fn coordinate_acceleration(v: DVec3, proper_acceleration: DVec3, c: f64) -> DVec3 {
+    acceleration_to_new_rf(-v, DVec3::ZERO, proper_acceleration, c)
+}
+
+/// Four-acceleration `(gamma^4 * (v . a) / c, gamma^2 * a + gamma^4 * (v . a) * v / c^2)` of the
+/// object moving with velocity `v` and acceleration `a`.
+pub(crate) fn four_acceleration(v: DVec3, a: DVec3, c: f64) -> DVec4 {
+    let gamma_sq = lorentz_factor_from_vel(v, c).powi(2);
+    let v_dot_a = v.dot(a);
+
+    let t = gamma_sq * gamma_sq * v_dot_a / c;
+    let spatial = gamma_sq * a + v * (t / c);
+
+    DVec4::new(t, spatial.x, spatial.y, spatial.z)
+}
+
+/// Inverse of [`four_acceleration`] for the object moving with velocity `v`.
+pub(crate) fn acceleration_from_four_acceleration(
+    v: DVec3,
+    four_acceleration: DVec4,
+    c: f64,
+) -> DVec3 {
+    let gamma_sq = lorentz_factor_from_vel(v, c).powi(2);
+    let spatial = DVec3::new(
+        four_acceleration.y,
+        four_acceleration.z,
+        four_acceleration.w,
+    );
+
+    (spatial - v * (four_acceleration.x / c)) / gamma_sq
+}
@@ -1,214 +1,822 @@
 use crate::*;
-use bevy::math::DVec3;
+use glam::DVec3;
 use nonempty::NonEmpty;
+use std::cmp::Ordering;
+
+/// Allowed distance between the pushed event and the last segment of the world line relative to
+/// the length of the segment, see [`WorldLine::push`].
+const SEGMENT_TOLERANCE: f64 = 1e-6;
 
 /// Event in object's world line.
 #[derive(Debug, Copy, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct WorldLineEvent {
     /// Space time coordinate of the object in main reference frame.
     pub coord: SpacetimeEvent,
 
-    /// Velocity of the object in main reference frame.
-    pub velocity: DVec3,
+    /// Rapidity of the object in main reference frame, see [`Rapidity`].
+    pub rapidity: Rapidity,
 
     /// Proper time of the object when this event happened.
     pub object_proper_time: f64,
+
+    /// Proper acceleration of the object in its rest frame at this event. It stays constant until
+    /// the next event, so the segment is a hyperbola (or a straight line if acceleration is zero).
+    pub proper_acceleration: DVec3,
 }
 
+impl WorldLineEvent {
+    /// Velocity of the object in main reference frame, it rounds to the speed of light for large
+    /// rapidities.
+    #[inline(always)]
+    pub fn velocity(&self, c: f64) -> DVec3 {
+        self.rapidity.to_velocity(c)
+    }
+
+    /// State of the object at this event.
+    pub fn motion_state(&self) -> MotionState {
+        MotionState {
+            coord: self.coord,
+            rapidity: self.rapidity,
+            proper_time: self.object_proper_time,
+        }
+    }
+
+    /// Get the state of the object after `delta_proper_time` of its proper time passed since this
+    /// event, following the segment which starts at this event.
+    pub fn advance(self, delta_proper_time: f64, c: f64) -> Self {
+        self.motion_state()
+            .advance(self.proper_acceleration, delta_proper_time, c)
+            .to_world_line_event(self.proper_acceleration)
+    }
+
+    /// Get proper time of the object when the time in main reference frame is `time`, following
+    /// the segment which starts at this event.
+    pub fn proper_time_at(&self, time: f64, c: f64) -> f64 {
+        let delta_proper_time = if self.proper_acceleration == DVec3::ZERO {
+            (time - self.coord.time) / self.rapidity.gamma()
+        } else {
+            calc_hyperbolic_main_frame_time(self, time - self.coord.time, c)
+        };
+
+        self.object_proper_time + delta_proper_time
+    }
+}
+
+/// World line of the object, list of events ordered by time.
+///
+/// Every event starts a segment of constant proper acceleration which lasts until the next event.
+/// The last segment lasts forever unless the world line is ended with [`WorldLine::end_at`].
+///
+/// Deserialized world lines are validated: events must be ordered by time and the end must not
+/// happen before the last event. The speed of light is not serialized, so velocities and the
+/// separation of events can't be checked, rebuild the world line with [`WorldLine::from_events`]
+/// to validate them.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "WorldLineData"))]
 pub struct WorldLine {
-    pub events: NonEmpty<WorldLineEvent>,
+    events: NonEmpty<WorldLineEvent>,
+    end: Option<WorldLineEvent>,
+}
+
+/// Serialized form of [`WorldLine`] before validation.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct WorldLineData {
+    events: Vec<WorldLineEvent>,
+    end: Option<WorldLineEvent>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<WorldLineData> for WorldLine {
+    type Error = WorldLineError;
+
+    fn try_from(data: WorldLineData) -> Result<Self, Self::Error> {
+        let events = NonEmpty::from_vec(data.events).ok_or(WorldLineError::Empty)?;
+
+        for (prev, event) in events.iter().zip(events.iter().skip(1)) {
+            if event.coord.time <= prev.coord.time {
+                return Err(WorldLineError::NotCausallyOrdered {
+                    prev_time: prev.coord.time,
+                    time: event.coord.time,
+                });
+            }
+        }
+
+        // the world line may end at the same time as its last event, see `WorldLine::end_at`
+        if let Some(end) = data.end {
+            let last_event = events.last();
+
+            if end.coord.time < last_event.coord.time {
+                return Err(WorldLineError::NotCausallyOrdered {
+                    prev_time: last_event.coord.time,
+                    time: end.coord.time,
+                });
+            }
+        }
+
+        Ok(Self {
+            events,
+            end: data.end,
+        })
+    }
 }
 
 impl WorldLine {
+    /// Create world line from the initial event without validation.
     pub fn new(init_event: WorldLineEvent) -> Self {
         Self {
             events: NonEmpty::new(init_event),
+            end: None,
+        }
+    }
+
+    /// Create world line from the initial event, checking that its velocity is less than the speed
+    /// of light.
+    pub fn try_new(init_event: WorldLineEvent, c: f64) -> Result<Self, WorldLineError> {
+        check_rapidity(init_event.rapidity, c)?;
+
+        Ok(Self::new(init_event))
+    }
+
+    /// Create world line from the list of events, validating every event as in
+    /// [`WorldLine::push`]. Proper time of the first event is preserved.
+    pub fn from_events(
+        events: impl IntoIterator<Item = WorldLineEvent>,
+        c: f64,
+    ) -> Result<Self, WorldLineError> {
+        let mut events = events.into_iter();
+
+        let mut world_line = Self::try_new(events.next().ok_or(WorldLineError::Empty)?, c)?;
+
+        for event in events {
+            world_line.push(event, c)?;
+        }
+
+        Ok(world_line)
+    }
+
+    pub fn events(&self) -> &NonEmpty<WorldLineEvent> {
+        &self.events
+    }
+
+    pub fn first_event(&self) -> &WorldLineEvent {
+        self.events.first()
+    }
+
+    pub fn last_event(&self) -> &WorldLineEvent {
+        self.events.last()
+    }
+
+    /// State of the object at the end of the world line (destruction, absorption, decay), `None`
+    /// if the object exists forever.
+    pub fn end_event(&self) -> Option<&WorldLineEvent> {
+        self.end.as_ref()
+    }
+
+    /// End the world line at the time `time` of main reference frame. Events after `time` are
+    /// removed and the segment containing `time` is cut at it, so the world line can be ended
+    /// before its planned maneuvers. No events can be added after the end.
+    pub fn end_at(&mut self, time: f64, c: f64) -> Result<&WorldLineEvent, WorldLineError> {
+        self.check_not_ended()?;
+
+        let first_event = self.first_event();
+
+        if time < first_event.coord.time {
+            return Err(WorldLineError::NotCausallyOrdered {
+                prev_time: first_event.coord.time,
+                time,
+            });
+        }
+
+        // the first event is always kept, so the index is at least 1
+        let index = self.partition_point(|event| event.coord.time <= time);
+        self.events.tail.truncate(index - 1);
+
+        let last_event = self.last_event();
+        let delta_proper_time = last_event.proper_time_at(time, c) - last_event.object_proper_time;
+        let end = last_event.advance(delta_proper_time, c);
+
+        Ok(self.end.insert(end))
+    }
+
+    fn check_not_ended(&self) -> Result<(), WorldLineError> {
+        match self.end {
+            Some(end) => Err(WorldLineError::Ended {
+                end_time: end.coord.time,
+            }),
+            None => Ok(()),
         }
     }
 
-    /// Find event from the world lint right before the given `event_position` in the reference
-    /// frame with the given `velocity`.
+    /// Append event to the end of the world line.
     ///
-    /// # Parameters
-    /// - `event_position`: Position of the event in the reference frame of the world line.
-    /// - `rf_velocity`: Velocity relative to the reference frame of the world line.
-    /// - `c`: Speed of light.
-    pub fn find_last_event(
-        &self,
-        event_position: SpacetimeEvent,
-        rf_velocity: DVec3,
+    /// The event must happen inside the future light cone of the last event, lie on the last
+    /// segment of the world line (up to rounding errors) and have velocity less than the speed of
+    /// light. Its `object_proper_time` is recomputed by following the last segment of the world
+    /// line up to the event's time. Velocity and proper acceleration of the event start the next
+    /// segment, so they may change abruptly.
+    pub fn push(
+        &mut self,
+        mut event: WorldLineEvent,
         c: f64,
-    ) -> Option<&WorldLineEvent> {
-        for event in self.events.iter().rev() {
-            let coord = event.coord - event_position;
-            let coord = coord.to_reference_frame(rf_velocity, c);
+    ) -> Result<&WorldLineEvent, WorldLineError> {
+        self.check_not_ended()?;
+        check_rapidity(event.rapidity, c)?;
+
+        let last_event = self.last_event();
+
+        if event.coord.time <= last_event.coord.time {
+            return Err(WorldLineError::NotCausallyOrdered {
+                prev_time: last_event.coord.time,
+                time: event.coord.time,
+            });
+        }
 
-            if coord.time <= 0.0 {
-                return Some(event);
+        let proper_time = last_event.proper_time_at(event.coord.time, c);
+        let expected = last_event.advance(proper_time - last_event.object_proper_time, c);
+
+        let delta = event.coord - last_event.coord;
+        let tolerance = SEGMENT_TOLERANCE * (1.0 + delta.pos.length() + c * delta.time);
+
+        // the segment is timelike, but for large rapidities the interval between its events is
+        // lost in rounding, so only the events off the segment are classified
+        if (event.coord.pos - expected.coord.pos).length() > tolerance {
+            let separation = last_event.coord.get_separation_with_tolerance(
+                event.coord,
+                c,
+                IntervalTolerance::Exact,
+            );
+
+            if separation != SpacetimeSeparation::Timelike {
+                return Err(WorldLineError::SpacelikeSegment {
+                    interval_squared: last_event.coord.spacetime_interval_squared(event.coord, c),
+                });
             }
+
+            return Err(WorldLineError::OffWorldLine {
+                pos: event.coord.pos,
+                expected_pos: expected.coord.pos,
+            });
         }
 
-        None
+        event.object_proper_time = proper_time;
+
+        self.events.push(event);
+
+        Ok(self.last_event())
     }
 
-    /// Get simultaneous event from the world line to the given `event_position` in the reference
-    /// frame with the given `velocity`.
+    /// Change velocity of the last event, i.e. the initial velocity of the last segment of the
+    /// world line. Useful when the velocity changes at the same time as the last event happened.
+    pub fn set_last_velocity(
+        &mut self,
+        velocity: DVec3,
+        c: f64,
+    ) -> Result<&WorldLineEvent, WorldLineError> {
+        self.check_not_ended()?;
+        check_velocity(velocity, c)?;
+
+        self.events.last_mut().rapidity = Rapidity::from_velocity(velocity, c);
+
+        Ok(self.last_event())
+    }
+
+    /// Same as [`WorldLine::set_last_velocity`] but takes rapidity, so it works for velocities
+    /// which round to the speed of light.
+    pub fn set_last_rapidity(
+        &mut self,
+        rapidity: Rapidity,
+        c: f64,
+    ) -> Result<&WorldLineEvent, WorldLineError> {
+        self.check_not_ended()?;
+        check_rapidity(rapidity, c)?;
+
+        self.events.last_mut().rapidity = rapidity;
+
+        Ok(self.last_event())
+    }
+
+    /// Change proper acceleration of the last event, i.e. the proper acceleration of the last
+    /// segment of the world line. Useful when the acceleration changes at the same time as the
+    /// last event happened.
+    pub fn set_last_proper_acceleration(
+        &mut self,
+        proper_acceleration: DVec3,
+    ) -> Result<&WorldLineEvent, WorldLineError> {
+        self.check_not_ended()?;
+
+        self.events.last_mut().proper_acceleration = proper_acceleration;
+
+        Ok(self.last_event())
+    }
+
+    /// Find event from the world lint right before the origin of the `frame` in its time.
     ///
     /// # Parameters
-    /// - `event_position`: Position of the event in the reference frame of the world line.
-    /// - `rf_velocity`: Velocity relative to the reference frame of the world line.
-    /// - `c`: Speed of light.
+    /// - `frame`: Reference frame of the observer described in the reference frame of the world
+    ///   line.
+    pub fn find_last_event(&self, frame: ReferenceFrame) -> Option<&WorldLineEvent> {
+        // world line is timelike, so observer's time of its events is increasing
+        let index = self.partition_point(|event| frame.event_to_frame(event.coord).time <= 0.0);
+
+        index
+            .checked_sub(1)
+            .and_then(|index| self.events.get(index))
+    }
+
+    /// Get simultaneous event from the world line to the origin of the `frame` in its time.
+    ///
+    /// # Parameters
+    /// - `frame`: Reference frame of the observer described in the reference frame of the world
+    ///   line.
+    ///
+    /// Returns [`WorldLineQueryError::NotYetVisible`] if the world line starts after the
+    /// observer's event in the observer's reference frame and
+    /// [`WorldLineQueryError::AlreadyEnded`] if it ends before it.
     pub fn get_current_event(
         &self,
-        event_position: SpacetimeEvent,
-        rf_velocity: DVec3,
-        c: f64,
-    ) -> Option<SimultaneousWorldLineEvent> {
-        let last_event = self.find_last_event(event_position, rf_velocity, c)?;
+        frame: ReferenceFrame,
+    ) -> Result<SimultaneousWorldLineEvent, WorldLineQueryError> {
+        let c = frame.c;
 
-        let relative_coord = (last_event.coord - event_position).to_reference_frame(rf_velocity, c);
-        let relative_velocity = velocity_to_new_rf(rf_velocity, last_event.velocity, c);
+        check_query_frame(&frame)?;
 
-        let observer_delta_time = -relative_coord.time;
+        if let Some(end) = self.end {
+            if frame.event_to_frame(end.coord).time <= 0.0 {
+                return Err(WorldLineQueryError::AlreadyEnded {
+                    end_coord: end.coord,
+                });
+            }
+        }
 
-        let relative_position = relative_coord.pos + relative_velocity * observer_delta_time;
+        let last_event = self
+            .find_last_event(frame)
+            .ok_or(WorldLineQueryError::NotYetVisible)?;
+
+        check_query_rapidity(last_event.rapidity, c)?;
+
+        if last_event.proper_acceleration != DVec3::ZERO {
+            let Some(delta_proper_time) =
+                calc_hyperbolic_simultaneity_time(last_event, frame.origin, frame.rapidity, c)
+            else {
+                let error = WorldLineQueryError::NumericalFailure {
+                    relative_coord: frame.event_to_frame(last_event.coord),
+                    relative_velocity: frame.rapidity_to_frame(last_event.rapidity).to_velocity(c),
+                };
+                log::debug!(
+                    "{error}; simultaneity hyperplane of the observer at {} (t = {})",
+                    frame.origin.pos,
+                    frame.origin.time
+                );
+
+                return Err(error);
+            };
+            let event = last_event.advance(delta_proper_time, c);
+
+            return Ok(SimultaneousWorldLineEvent {
+                relative_position: frame.event_to_frame(event.coord).pos,
+                proper_time: event.object_proper_time,
+                velocity: event.velocity(c),
+                relative_velocity: frame.rapidity_to_frame(event.rapidity).to_velocity(c),
+                coord: event.coord,
+            });
+        }
 
-        // gamma factors relative to main reference frame
-        let object_gamma = lorentz_factor_from_vel(last_event.velocity, c);
-        let observer_gamma = lorentz_factor_from_vel(rf_velocity, c);
+        let relative_coord = frame.event_to_frame(last_event.coord);
+        let relative_velocity = frame.rapidity_to_frame(last_event.rapidity).to_velocity(c);
 
-        let object_proper_time =
-            last_event.object_proper_time + observer_delta_time * observer_gamma / object_gamma;
+        let observer_delta_time = -relative_coord.time;
+
+        let relative_position = relative_coord.pos + relative_velocity * observer_delta_time;
+        let coord = frame.event_from_frame(SpacetimeEvent::new(relative_position));
 
-        Some(SimultaneousWorldLineEvent {
+        Ok(SimultaneousWorldLineEvent {
             relative_position,
-            proper_time: object_proper_time,
-            velocity: last_event.velocity,
+            proper_time: last_event.proper_time_at(coord.time, c),
+            velocity: last_event.velocity(c),
             relative_velocity,
-            coord: last_event.coord,
+            coord,
         })
     }
 
-    /// Find last event from the world line which is inside the observer's past light cone.
+    /// Find last event from the world line which is inside the past light cone of the origin of
+    /// the observer's `frame`.
     ///
     /// Returns `None` if the world line start is outside the observer's past light cone.
-    pub fn find_last_visible_event(
-        &self,
-        event_position: SpacetimeEvent,
-        c: f64,
-    ) -> Option<&WorldLineEvent> {
-        let mut prev_event = self.events.first();
+    pub fn find_last_visible_event(&self, frame: ReferenceFrame) -> Option<&WorldLineEvent> {
+        // exact light cone, so the last visible event is never after the intersection
+        let light_cone = frame
+            .origin
+            .light_cone(frame.c)
+            .with_tolerance(IntervalTolerance::Exact);
+
+        // if an event is outside the past light cone then all the following events are outside
+        // it too, so the trajectory between the last event inside and the first event outside
+        // intersects with the observer's past light cone
+        let index = self.partition_point(|event| light_cone.past_contains(event.coord));
+
+        index
+            .checked_sub(1)
+            .and_then(|index| self.events.get(index))
+    }
 
-        if !event_position.light_cone(c).past_contains(prev_event.coord) {
-            // world line start is outside the observer's past light cone and can't be seen yet
+    /// Get the state of the object when its clock reads `proper_time`, interpolating along the
+    /// segment which contains it.
+    ///
+    /// Returns `None` if `proper_time` is before the start or after the end of the world line.
+    pub fn event_at_proper_time(&self, proper_time: f64, c: f64) -> Option<WorldLineEvent> {
+        if self
+            .end
+            .is_some_and(|end| proper_time > end.object_proper_time)
+        {
             return None;
         }
 
-        for next_event in self.events.iter().skip(1) {
-            if !event_position.light_cone(c).past_contains(next_event.coord) {
-                // if next event is outside past light cone then the trajectory between prev_event
-                // and next_event intersects with the observer's past light cone
-                break;
-            }
+        let index = self.partition_point(|event| event.object_proper_time <= proper_time);
+        let event = self.events.get(index.checked_sub(1)?)?;
+
+        Some(event.advance(proper_time - event.object_proper_time, c))
+    }
+
+    /// Get the state of the object at the time `time` of main reference frame, interpolating along
+    /// the segment which contains it.
+    ///
+    /// Returns `None` if `time` is before the start or after the end of the world line.
+    pub fn event_at_time(&self, time: f64, c: f64) -> Option<WorldLineEvent> {
+        let proper_time = self.proper_time_at(time, c)?;
+
+        self.event_at_proper_time(proper_time, c)
+    }
 
-            prev_event = next_event;
+    /// Get the reading of the object's clock at the time `time` of main reference frame.
+    ///
+    /// Returns `None` if `time` is before the start or after the end of the world line.
+    pub fn proper_time_at(&self, time: f64, c: f64) -> Option<f64> {
+        if self.end.is_some_and(|end| time > end.coord.time) {
+            return None;
         }
 
-        Some(prev_event)
+        let index = self.partition_point(|event| event.coord.time <= time);
+        let event = self.events.get(index.checked_sub(1)?)?;
+
+        Some(event.proper_time_at(time, c))
+    }
+
+    /// Find the state of the object when the light signal emitted at `emission` reaches it, i.e.
+    /// the intersection of the world line with the future light cone of `emission`.
+    ///
+    /// Returns `None` if the signal never reaches the object: the world line starts inside the
+    /// future light cone, ends before the signal arrives or the object outruns the signal (e.g.
+    /// behind the horizon of the eternally accelerated object).
+    pub fn receive_light_signal(&self, emission: SpacetimeEvent, c: f64) -> Option<WorldLineEvent> {
+        let light_cone = emission
+            .light_cone(c)
+            .with_tolerance(IntervalTolerance::Exact);
+
+        // if an event is inside the future light cone then all the following events are inside
+        // it too, so the trajectory between the last event outside and the first event inside
+        // intersects with the future light cone
+        let index = self.partition_point(|event| !light_cone.future_contains(event.coord));
+        let start = self.events.get(index.checked_sub(1)?)?;
+
+        let delta_proper_time = if start.proper_acceleration == DVec3::ZERO {
+            calc_inertial_intersection_time(start, emission, Nappe::Future, c)?
+        } else {
+            calc_hyperbolic_intersection_time(start, emission, Nappe::Future, c)?
+        };
+
+        let reception = start.advance(delta_proper_time, c);
+
+        if self
+            .end
+            .is_some_and(|end| reception.object_proper_time > end.object_proper_time)
+        {
+            return None;
+        }
+
+        Some(reception)
+    }
+
+    /// Binary search for the first event which doesn't match the `predicate`. Events matching the
+    /// `predicate` must form a prefix of the world line.
+    fn partition_point(&self, predicate: impl Fn(&WorldLineEvent) -> bool) -> usize {
+        let result = self.events.binary_search_by(|event| {
+            if predicate(event) {
+                Ordering::Less
+            } else {
+                Ordering::Greater
+            }
+        });
+
+        match result {
+            Ok(index) | Err(index) => index,
+        }
     }
 
     /// Same as [`WorldLine::get_current_event`] but return event on world line which intersects
     /// with the observer's past light cone.
     ///
+    /// The world line is timelike, so it intersects the past light cone at most once and the
+    /// returned event is the only image of the object seen by the observer.
+    ///
     /// # Parameters
-    /// - `event_position`: Position of the event in the reference frame of the world line.
-    /// - `rf_velocity`: Velocity relative to the reference frame of the world line.
-    /// - `c`: Speed of light.
+    /// - `frame`: Reference frame of the observer described in the reference frame of the world
+    ///   line.
+    ///
+    /// Returns [`WorldLineQueryError::NotYetVisible`] if the light from the world line start
+    /// hasn't reached the observer yet and [`WorldLineQueryError::AlreadyEnded`] if the light from
+    /// its end has already reached the observer. Until then the object stays visible even if it
+    /// has already ended in the observer's reference frame.
     pub fn get_visible_event(
         &self,
-        event_position: SpacetimeEvent,
-        rf_velocity: DVec3,
-        c: f64,
-    ) -> Option<VisibleWorldLineEvent> {
-        let last_visible_event = self.find_last_visible_event(event_position, c)?;
+        frame: ReferenceFrame,
+    ) -> Result<VisibleWorldLineEvent, WorldLineQueryError> {
+        let c = frame.c;
 
-        let relative_velocity = velocity_to_new_rf(rf_velocity, last_visible_event.velocity, c);
+        check_query_frame(&frame)?;
 
-        // last visible event position in observer's reference frame
-        let relative_last_event =
-            (last_visible_event.coord - event_position).to_reference_frame(rf_velocity, c);
-
-        // find time of first light cone intersection
-        let t_intersect = calc_intersection_time(relative_last_event, relative_velocity, c);
-        let Some(t_intersect) = t_intersect.into_iter().min_by(|a, b| a.total_cmp(b)) else {
-            // TODO add proper warnings
-            println!("!!!No intersection found!!! coord={relative_last_event:?}; vel={relative_velocity:?}");
-            return None;
-        };
-        if t_intersect > 0.0 {
-            // TODO add proper warnings
-            println!("!!!Intersection is in the future!!! {t_intersect:?}");
-            return None;
+        if let Some(end) = self.end {
+            if frame.origin.light_cone(c).past_contains(end.coord) {
+                return Err(WorldLineQueryError::AlreadyEnded {
+                    end_coord: end.coord,
+                });
+            }
         }
 
-        let delta_time = t_intersect - relative_last_event.time;
-        let pos_intersect = relative_last_event.pos + relative_velocity * delta_time;
+        let last_visible_event = self
+            .find_last_visible_event(frame)
+            .ok_or(WorldLineQueryError::NotYetVisible)?;
 
-        let intersect_event = SpacetimeEvent::new(pos_intersect).with_time(t_intersect);
+        check_query_rapidity(last_visible_event.rapidity, c)?;
 
-        let relative_gamma = lorentz_factor_from_vel(relative_velocity, c);
-        let delta_proper_time = delta_time / relative_gamma;
+        let delta_proper_time = if last_visible_event.proper_acceleration == DVec3::ZERO {
+            calc_inertial_intersection_time(last_visible_event, frame.origin, Nappe::Past, c)
+        } else {
+            calc_hyperbolic_intersection_time(last_visible_event, frame.origin, Nappe::Past, c)
+        };
+        let Some(delta_proper_time) = delta_proper_time else {
+            // the last visible event is inside the past light cone, so the intersection exists
+            // unless floating point errors get in the way
+            let error = WorldLineQueryError::NumericalFailure {
+                relative_coord: frame.event_to_frame(last_visible_event.coord),
+                relative_velocity: frame
+                    .rapidity_to_frame(last_visible_event.rapidity)
+                    .to_velocity(c),
+            };
+            log::debug!(
+                "{error}; light cone of the observer at {} (t = {})",
+                frame.origin.pos,
+                frame.origin.time
+            );
+
+            return Err(error);
+        };
+        let event = last_visible_event.advance(delta_proper_time, c);
 
-        Some(VisibleWorldLineEvent {
-            relative_coord: intersect_event,
-            proper_time: last_visible_event.object_proper_time + delta_proper_time,
-            velocity: last_visible_event.velocity,
-            relative_velocity,
+        Ok(VisibleWorldLineEvent {
+            relative_coord: frame.event_to_frame(event.coord),
+            proper_time: event.object_proper_time,
+            velocity: event.velocity(c),
+            relative_velocity: frame.rapidity_to_frame(event.rapidity).to_velocity(c),
         })
     }
 }
 
-fn calc_intersection_time(cord: SpacetimeEvent, vel: DVec3, c: f64) -> Vec<f64> {
-    let vx_sq = vel.x * vel.x;
-    let vy_sq = vel.y * vel.y;
-    let vz_sq = vel.z * vel.z;
+#[inline(always)]
+fn is_subluminal(velocity: DVec3, c: f64) -> bool {
+    !velocity.is_nan() && velocity.length_squared() < c * c
+}
 
-    let v_sq = vx_sq + vy_sq + vz_sq;
+fn check_velocity(velocity: DVec3, c: f64) -> Result<(), WorldLineError> {
+    if !is_subluminal(velocity, c) {
+        return Err(WorldLineError::SuperluminalVelocity { velocity, c });
+    }
 
-    let a = c * c - v_sq;
+    Ok(())
+}
 
-    let b = -2.0
-        * ((cord.pos.x * vel.x - vx_sq * cord.time)
-            + (cord.pos.y * vel.y - vy_sq * cord.time)
-            + (cord.pos.z * vel.z - vz_sq * cord.time));
+fn check_rapidity(rapidity: Rapidity, c: f64) -> Result<(), WorldLineError> {
+    if !rapidity.magnitude.is_finite() {
+        return Err(WorldLineError::SuperluminalVelocity {
+            velocity: rapidity.to_velocity(c),
+            c,
+        });
+    }
+
+    Ok(())
+}
+
+fn check_query_rapidity(rapidity: Rapidity, c: f64) -> Result<(), WorldLineQueryError> {
+    if !rapidity.magnitude.is_finite() {
+        let error = WorldLineQueryError::DegenerateVelocity {
+            velocity: rapidity.to_velocity(c),
+            c,
+        };
+        log::debug!("{error}; object rapidity {}", rapidity.magnitude);
 
-    let c = -((cord.pos.x * cord.pos.x - 2.0 * cord.pos.x * vel.x * cord.time
-        + vx_sq * cord.time * cord.time)
-        + (cord.pos.y * cord.pos.y - 2.0 * cord.pos.y * vel.y * cord.time
-            + vy_sq * cord.time * cord.time)
-        + (cord.pos.z * cord.pos.z - 2.0 * cord.pos.z * vel.z * cord.time
-            + vz_sq * cord.time * cord.time));
+        return Err(error);
+    }
 
-    let double_a = 2.0 * a;
-    let discriminant = b * b - 2.0 * double_a * c;
+    Ok(())
+}
+
+/// Rapidity of the observer's frame is used instead of its velocity, which rounds to the speed of
+/// light long before the rapidity stops being finite.
+fn check_query_frame(frame: &ReferenceFrame) -> Result<(), WorldLineQueryError> {
+    if !frame.rapidity.magnitude.is_finite() {
+        let error = WorldLineQueryError::DegenerateVelocity {
+            velocity: frame.velocity(),
+            c: frame.c,
+        };
+        log::debug!("{error}; observer rapidity {}", frame.rapidity.magnitude);
+
+        return Err(error);
+    }
+
+    Ok(())
+}
+
+/// Half of the light cone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Nappe {
+    Past,
+    Future,
+}
+
+/// Find proper time (relative to `start`) at which the inertial segment starting at `start`
+/// intersects the given `nappe` of the light cone of `observer_coord`.
+///
+/// The equation is solved in the rest frame of `start`, where the object stays at the origin. The
+/// boost uses rapidity, so unlike solving it in the observer's frame there is no `c^2 - v^2` to
+/// cancel when the relative velocity rounds to the speed of light.
+fn calc_inertial_intersection_time(
+    start: &WorldLineEvent,
+    observer_coord: SpacetimeEvent,
+    nappe: Nappe,
+    c: f64,
+) -> Option<f64> {
+    let observer = ReferenceFrame::new(start.coord, c)
+        .with_rapidity(start.rapidity)
+        .event_to_frame(observer_coord);
+
+    let light_travel_time = observer.pos.length() / c;
+
+    let delta_proper_time = match nappe {
+        Nappe::Past => observer.time - light_travel_time,
+        Nappe::Future => observer.time + light_travel_time,
+    };
+
+    // `start` is inside the past light cone or outside the future one, so the intersection can't
+    // be before it
+    (delta_proper_time >= 0.0).then_some(delta_proper_time)
+}
+
+/// Find proper time (relative to `start`) at which the hyperbolic segment starting at `start`
+/// intersects the given `nappe` of the light cone of `observer_coord`.
+///
+/// The equation is solved in the rest frame of `start` where the segment is
+/// `x = R * (cosh(s) - 1) * n`, `c * t = R * sinh(s)` with `R = c^2 / a` and `s = a * tau / c`.
+/// Substituting `y = e^s - 1` turns the light cone equation into a quadratic in `y`.
+fn calc_hyperbolic_intersection_time(
+    start: &WorldLineEvent,
+    observer_coord: SpacetimeEvent,
+    nappe: Nappe,
+    c: f64,
+) -> Option<f64> {
+    let acceleration = start.proper_acceleration.length();
+    let direction = start.proper_acceleration / acceleration;
+    let radius = c * c / acceleration;
+
+    // observer event in the rest frame of the segment start
+    let observer = ReferenceFrame::new(start.coord, c)
+        .with_rapidity(start.rapidity)
+        .event_to_frame(observer_coord);
+
+    let ct = c * observer.time;
+    let interval = ct * ct - observer.pos.length_squared();
+
+    let a = observer.pos.dot(direction) + radius - ct;
+    let b = interval / radius - 2.0 * ct;
+    let c_coef = interval / radius;
+
+    let roots = solve_quadratic(a, b, c_coef)
+        .into_iter()
+        .filter(|y| *y > -1.0)
+        .map(f64::ln_1p);
+
+    let s = match nappe {
+        Nappe::Past => {
+            // the earliest intersection along the segment lies on the past light cone
+            let s = roots.min_by(|a, b| a.total_cmp(b))?;
+
+            if radius * s.sinh() > ct {
+                return None;
+            }
+
+            s
+        }
+        Nappe::Future => roots
+            .filter(|s| radius * s.sinh() >= ct)
+            .min_by(|a, b| a.total_cmp(b))?,
+    };
+
+    Some(s * c / acceleration)
+}
+
+/// Find proper time (relative to `start`) at which the hyperbolic segment starting at `start`
+/// reaches the time `start.coord.time + delta_time` of main reference frame.
+///
+/// Same as [`calc_hyperbolic_simultaneity_time`] for the observer at rest in main reference frame,
+/// but solved in main reference frame, so it stays precise when the rapidity of `start` is large.
+/// With `y = e^s - 1` the time of the segment is
+/// `c * delta_time / R = (A * y^2 + 2 * cosh(r) * y) / (2 * (1 + y))`, where `r` is the rapidity
+/// of `start` and `A = cosh(r) + sinh(r) * cos(angle between the velocity and the acceleration)`.
+fn calc_hyperbolic_main_frame_time(start: &WorldLineEvent, delta_time: f64, c: f64) -> f64 {
+    let acceleration = start.proper_acceleration.length();
+    let direction = start.proper_acceleration / acceleration;
+    let radius = c * c / acceleration;
+
+    let rapidity = start.rapidity;
+    let sinh = rapidity.magnitude.sinh();
+    let cosh = rapidity.gamma();
+
+    // `cosh + sinh * cos` without cancellation when the acceleration is against the motion
+    let a = (-rapidity.magnitude).exp()
+        + sinh * 0.5 * (rapidity.direction + direction).length_squared();
+    let k = c * delta_time / radius;
+
+    // the roots are on both sides of `y = -1`, the larger one has `e^s > 0`
+    let s = solve_quadratic(a, 2.0 * (cosh - k), -2.0 * k)
+        .into_iter()
+        .max_by(|a, b| a.total_cmp(b))
+        .map_or(f64::NAN, f64::ln_1p);
+
+    s * c / acceleration
+}
+
+/// Find proper time (relative to `start`) at which the hyperbolic segment starting at `start`
+/// crosses the simultaneity hyperplane of the observer at `observer_coord` moving with
+/// `observer_rapidity`.
+///
+/// Returns `None` if floating point errors leave no root with `e^s > 0`.
+fn calc_hyperbolic_simultaneity_time(
+    start: &WorldLineEvent,
+    observer_coord: SpacetimeEvent,
+    observer_rapidity: Rapidity,
+    c: f64,
+) -> Option<f64> {
+    let acceleration = start.proper_acceleration.length();
+    let direction = start.proper_acceleration / acceleration;
+    let radius = c * c / acceleration;
+
+    // observer event and velocity in the rest frame of the segment start
+    let start_frame = ReferenceFrame::new(start.coord, c).with_rapidity(start.rapidity);
+    let observer = start_frame.event_to_frame(observer_coord);
+    let velocity = start_frame
+        .rapidity_to_frame(observer_rapidity)
+        .to_velocity(c);
+
+    let offset = 2.0 * (velocity.dot(observer.pos) - c * c * observer.time) / radius;
+
+    let a = c - velocity.dot(direction);
+    let b = 2.0 * c + offset;
+
+    // only one root has `e^s > 0`, and it is the largest one
+    let s = solve_quadratic(a, b, offset)
+        .into_iter()
+        .max_by(|a, b| a.total_cmp(b))
+        .filter(|y| *y > -1.0)
+        .map(f64::ln_1p)?;
+
+    Some(s * c / acceleration)
+}
+
+/// Numerically stable solution of `a * x^2 + b * x + c = 0`.
+fn solve_quadratic(a: f64, b: f64, c: f64) -> Vec<f64> {
+    let discriminant = b * b - 4.0 * a * c;
 
     if discriminant < 0.0 {
         return vec![];
     }
 
-    if discriminant == 0.0 {
-        let t = -b / double_a;
-        return vec![t];
+    let q = -0.5 * (b + b.signum() * discriminant.sqrt());
+
+    if q == 0.0 {
+        return vec![0.0];
     }
 
-    let d_sqrt = discriminant.sqrt();
+    if a == 0.0 {
+        return vec![c / q];
+    }
 
-    vec![(-b + d_sqrt) / double_a, (-b - d_sqrt) / double_a]
+    vec![q / a, c / q]
 }
 
 /// World line event simultaneous to the observer's event in the observer's reference frame.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SimultaneousWorldLineEvent {
     /// Position of the event in observer's reference frame.
     pub relative_position: DVec3,
@@ -224,6 +832,7 @@ pub struct SimultaneousWorldLineEvent {
 
 /// World line event visible to the observer.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct VisibleWorldLineEvent {
     /// Position of the event in observer's reference frame relative to the observer's event.
     pub relative_coord: SpacetimeEvent,
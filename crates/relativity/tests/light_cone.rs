@@ -1,5 +1,5 @@
-use bevy::math::DVec3;
-use relativity::{SpacetimeEvent, SpacetimeSeparation};
+use glam::DVec3;
+use relativity::{IntervalTolerance, SpacetimeEvent, SpacetimeSeparation};
 
 #[test]
 fn test_light_cone_timelike_separation() {
@@ -33,3 +33,85 @@ fn test_light_cone_timelike_separation() {
     assert!(!event_1.light_cone(c).future_contains(event_0));
     assert!(event_0.light_cone(c).future_contains(event_1));
 }
+
+#[test]
+fn test_light_cone_lightlike_separation() {
+    let c = 3.0;
+
+    let origin = SpacetimeEvent::new(DVec3::new(1.0, 2.0, 3.0)).with_time(0.5);
+
+    // on the future cone up to rounding errors
+    let direction = DVec3::new(0.3, -0.4, 0.5).normalize();
+    let on_cone =
+        SpacetimeEvent::new(origin.pos + direction * 7.1).with_time(origin.time + 7.1 / c);
+
+    assert_eq!(
+        origin.get_separation(on_cone, c),
+        SpacetimeSeparation::Lightlike
+    );
+    assert!(origin.light_cone(c).surface_contains(on_cone));
+    assert!(origin.light_cone(c).future_contains(on_cone));
+    assert!(!origin.light_cone(c).past_contains(on_cone));
+    assert!(origin.light_cone(c).contains(on_cone));
+
+    let exactly_on_cone = SpacetimeEvent::new(DVec3::new(4.0, 2.0, 3.0)).with_time(1.5);
+    assert_eq!(
+        origin.get_separation_with_tolerance(exactly_on_cone, c, IntervalTolerance::Exact),
+        SpacetimeSeparation::Lightlike
+    );
+
+    let slightly_inside = on_cone.with_time(on_cone.time + 1e-11);
+    assert_eq!(
+        origin.get_separation(slightly_inside, c),
+        SpacetimeSeparation::Lightlike
+    );
+    assert_eq!(
+        origin.get_separation_with_tolerance(slightly_inside, c, IntervalTolerance::Exact),
+        SpacetimeSeparation::Timelike
+    );
+    assert_eq!(
+        origin.get_separation_with_tolerance(slightly_inside, c, IntervalTolerance::Absolute(1e-6)),
+        SpacetimeSeparation::Lightlike
+    );
+
+    let slightly_outside = on_cone.with_time(on_cone.time - 1e-6);
+    assert_eq!(
+        origin.get_separation(slightly_outside, c),
+        SpacetimeSeparation::Spacelike
+    );
+    assert!(!origin.light_cone(c).contains(slightly_outside));
+    assert!(origin
+        .light_cone(c)
+        .with_tolerance(IntervalTolerance::Relative(1e-4))
+        .future_contains(slightly_outside));
+}
+
+#[test]
+fn test_causal_relations() {
+    let c = 1.0;
+
+    let past = SpacetimeEvent::new(DVec3::ZERO);
+    let future = SpacetimeEvent::new(DVec3::ZERO).with_time(4.0);
+
+    let inside = SpacetimeEvent::new(DVec3::new(1.0, 0.0, 0.0)).with_time(2.0);
+    let on_edge = SpacetimeEvent::new(DVec3::new(0.0, 2.0, 0.0)).with_time(2.0);
+    let outside = SpacetimeEvent::new(DVec3::new(0.0, 0.0, 2.5)).with_time(2.0);
+    let later = SpacetimeEvent::new(DVec3::new(0.5, 0.0, 0.0)).with_time(5.0);
+
+    assert!(past.can_influence(inside, c));
+    assert!(!inside.can_influence(past, c));
+    assert!(inside.is_in_causal_future(past, c));
+    assert!(past.is_in_causal_past(inside, c));
+    assert!(!past.is_in_causal_future(inside, c));
+
+    // light signal connects events on the light cone
+    assert!(past.can_influence(on_edge, c));
+    assert!(on_edge.can_influence(future, c));
+    assert!(!past.can_influence(outside, c));
+    assert!(!outside.can_influence(past, c));
+
+    assert!(inside.is_in_causal_diamond(past, future, c));
+    assert!(on_edge.is_in_causal_diamond(past, future, c));
+    assert!(!outside.is_in_causal_diamond(past, future, c));
+    assert!(!later.is_in_causal_diamond(past, future, c));
+}
@@ -0,0 +1,82 @@
+use glam::DVec3;
+use relativity::*;
+
+const EPSILON: f64 = 1e-9;
+
+fn assert_vec_eq(a: DVec3, b: DVec3, epsilon: f64) {
+    assert!((a - b).length() < epsilon, "{a:?} != {b:?}");
+}
+
+/// Force `dp/dt` producing acceleration `a` of the object with rest mass `mass`.
+fn force(mass: f64, v: DVec3, a: DVec3, c: f64) -> DVec3 {
+    let gamma = lorentz_factor_from_vel(v, c);
+
+    mass * (gamma * a + gamma.powi(3) * v.dot(a) * v / (c * c))
+}
+
+#[test]
+fn test_coordinate_acceleration_matches_hyperbolic_motion() {
+    let c = 2.0;
+    let start = WorldLineEvent {
+        coord: SpacetimeEvent::ZERO,
+        rapidity: Rapidity::from_velocity(DVec3::new(0.7, -1.2, 0.4), c),
+        object_proper_time: 0.0,
+        proper_acceleration: DVec3::new(0.5, 0.3, -0.9),
+    };
+
+    let dt = 1e-4;
+    let before = start.advance(-dt, c);
+    let after = start.advance(dt, c);
+    let expected =
+        (after.velocity(c) - before.velocity(c)) / (after.coord.time - before.coord.time);
+
+    let acceleration = coordinate_acceleration(start.velocity(c), start.proper_acceleration, c);
+    assert_vec_eq(acceleration, expected, 1e-6);
+
+    assert_vec_eq(
+        proper_acceleration(start.velocity(c), acceleration, c),
+        start.proper_acceleration,
+        EPSILON,
+    );
+}
+
+#[test]
+fn test_proper_acceleration_is_invariant() {
+    let c = 1.0;
+    let v = DVec3::new(0.3, 0.5, -0.2);
+    let a = DVec3::new(-0.4, 0.1, 0.8);
+    let proper = proper_acceleration(v, a, c);
+
+    let rf_delta_v = DVec3::new(-0.6, 0.2, 0.3);
+    let new_v = velocity_to_new_rf(rf_delta_v, v, c);
+    let new_a = acceleration_to_new_rf(rf_delta_v, v, a, c);
+
+    // rest frames differ only by Wigner rotation
+    let new_proper = proper_acceleration(new_v, new_a, c);
+    assert!((new_proper.length() - proper.length()).abs() < EPSILON);
+
+    // proper acceleration of the object at rest is its coordinate acceleration
+    assert_vec_eq(proper_acceleration(DVec3::ZERO, a, c), a, EPSILON);
+}
+
+#[test]
+fn test_force_transform_matches_acceleration_transform() {
+    let c = 3.0;
+    let mass = 2.5;
+    let v = DVec3::new(1.0, -0.5, 2.0);
+    let a = DVec3::new(0.3, 0.7, -1.1);
+    let rf_delta_v = DVec3::new(-1.5, 1.2, 0.4);
+
+    let new_v = velocity_to_new_rf(rf_delta_v, v, c);
+    let new_a = acceleration_to_new_rf(rf_delta_v, v, a, c);
+
+    assert_vec_eq(
+        force_to_new_rf(rf_delta_v, v, force(mass, v, a, c), c),
+        force(mass, new_v, new_a, c),
+        EPSILON,
+    );
+
+    // force is the same in the instantaneous rest frame if it is parallel to velocity
+    let f = v.normalize() * 4.0;
+    assert_vec_eq(force_to_new_rf(v, v, f, c), f, EPSILON);
+}
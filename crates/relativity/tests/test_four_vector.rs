@@ -0,0 +1,97 @@
+use glam::DVec3;
+use relativity::*;
+
+const EPSILON: f64 = 1e-10;
+
+#[test]
+fn test_four_vector_norm_is_invariant() {
+    let c = 2.0;
+    let position = FourPosition::from_event(
+        SpacetimeEvent::new(DVec3::new(1.0, -3.0, 2.0)).with_time(4.0),
+        c,
+    );
+    let frame_velocity = DVec3::new(0.5, 1.2, -0.3);
+
+    let transformed = position.to_reference_frame(frame_velocity, c);
+
+    assert!((transformed.norm_squared() - position.norm_squared()).abs() < EPSILON);
+    assert_eq!(position.separation(), SpacetimeSeparation::Timelike);
+
+    let spacelike = FourPosition::new(1.0, DVec3::new(0.0, 2.0, 0.0));
+    assert_eq!(spacelike.separation(), SpacetimeSeparation::Spacelike);
+    assert!((spacelike.norm() - 3.0_f64.sqrt()).abs() < EPSILON);
+
+    let photon =
+        FourMomentum::new(5.0, DVec3::new(3.0, 0.0, 4.0)).to_reference_frame(frame_velocity, c);
+    assert_eq!(photon.separation(), SpacetimeSeparation::Lightlike);
+}
+
+#[test]
+fn test_four_vector_matches_lorentz_transformation() {
+    // collinear boost with gamma = 1.25: t' = gamma * (t - v * x / c^2), x' = gamma * (x - v * t)
+    let transformed = FourPosition::from_event(
+        SpacetimeEvent::new(DVec3::new(2.0, 3.0, -1.0)).with_time(1.0),
+        1.0,
+    )
+    .to_reference_frame(DVec3::new(0.6, 0.0, 0.0), 1.0)
+    .to_event(1.0);
+
+    assert!((transformed.time - -0.25).abs() < EPSILON);
+    assert!((transformed.pos - DVec3::new(1.75, 3.0, -1.0)).length() < EPSILON);
+
+    // general boost written out:
+    // t' = gamma * (t - v.x / c^2), x' = x + ((gamma - 1) * v.x / v^2 - gamma * t) * v
+    let c = 3.0;
+    let event = SpacetimeEvent::new(DVec3::new(2.0, 1.0, -1.0)).with_time(-2.0);
+    let v = DVec3::new(-1.0, 0.5, 2.0);
+
+    let gamma = 1.0 / (1.0 - v.length_squared() / (c * c)).sqrt();
+    let v_dot_x = v.dot(event.pos);
+    let expected_time = gamma * (event.time - v_dot_x / (c * c));
+    let expected_pos =
+        event.pos + v * ((gamma - 1.0) * v_dot_x / v.length_squared() - gamma * event.time);
+
+    let transformed = FourPosition::from_event(event, c)
+        .to_reference_frame(v, c)
+        .to_event(c);
+
+    assert!((transformed.pos - expected_pos).length() < EPSILON);
+    assert!((transformed.time - expected_time).abs() < EPSILON);
+}
+
+#[test]
+fn test_four_velocity_and_momentum() {
+    let c = 2.0;
+    let mass = 3.0;
+    let velocity = DVec3::new(1.0, 0.5, -0.7);
+
+    let event = WorldLineEvent {
+        coord: SpacetimeEvent::ZERO,
+        rapidity: Rapidity::from_velocity(velocity, c),
+        object_proper_time: 0.0,
+        proper_acceleration: DVec3::ZERO,
+    };
+
+    let four_velocity = event.four_velocity(c);
+    assert!((four_velocity.norm() - c).abs() < EPSILON);
+    assert!((four_velocity.velocity(c) - velocity).length() < EPSILON);
+    assert!((four_velocity.gamma(c) - lorentz_factor_from_vel(velocity, c)).abs() < EPSILON);
+
+    // four-velocity of the object is at rest in its own reference frame
+    let rest = four_velocity.to_reference_frame(velocity, c);
+    assert!((rest.t - c).abs() < EPSILON);
+    assert!(rest.spatial.length() < EPSILON);
+
+    let momentum = event.four_momentum(mass, c);
+    let energy = momentum.energy(c);
+    let expected_energy_sq = (momentum.spatial.length() * c).powi(2) + (mass * c * c).powi(2);
+
+    assert!((momentum.mass(c) - mass).abs() < EPSILON);
+    assert!((energy.powi(2) - expected_energy_sq).abs() < 1e-8);
+    let expected_momentum = FourMomentum::from_velocity(mass, velocity, c);
+    assert!((momentum.to_dvec4() - expected_momentum.to_dvec4()).length() < EPSILON);
+    assert_eq!(
+        four_velocity * mass,
+        FourVelocity::new(momentum.t, momentum.spatial)
+    );
+}
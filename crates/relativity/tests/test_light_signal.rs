@@ -0,0 +1,146 @@
+use glam::DVec3;
+use relativity::{Rapidity, SpacetimeEvent, WorldLine, WorldLineEvent};
+
+fn world_line(pos: DVec3, rapidity: Rapidity, proper_acceleration: DVec3) -> WorldLine {
+    WorldLine::new(WorldLineEvent {
+        coord: SpacetimeEvent::new(pos),
+        rapidity,
+        object_proper_time: 0.0,
+        proper_acceleration,
+    })
+}
+
+#[test]
+fn test_light_signal_between_objects_at_rest() {
+    let c = 2.0;
+
+    let sender = world_line(DVec3::ZERO, Rapidity::ZERO, DVec3::ZERO);
+    let target = world_line(DVec3::new(0.0, 3.0, 4.0), Rapidity::ZERO, DVec3::ZERO);
+
+    let signal = sender
+        .send_light_signal(1.0, &target, c)
+        .expect("signal is received");
+    assert_eq!(
+        signal.emission.coord,
+        SpacetimeEvent::new(DVec3::ZERO).with_time(1.0)
+    );
+    assert!((signal.reception.coord.time - 3.5).abs() < 1e-12);
+    assert!((signal.reception.object_proper_time - 3.5).abs() < 1e-12);
+
+    let echo = sender
+        .radar_ranging(1.0, &target, c)
+        .expect("echo is received");
+    assert!((echo.distance(c) - 5.0).abs() < 1e-12);
+    assert!((echo.time() - 3.5).abs() < 1e-12);
+    assert!((echo.round_trip_time() - 5.0).abs() < 1e-12);
+
+    // signal emitted before the sender exists
+    assert!(sender.send_light_signal(-1.0, &target, c).is_none());
+
+    // signal passed the target's position before the target was created
+    let late_target = WorldLine::new(WorldLineEvent {
+        coord: SpacetimeEvent::new(DVec3::new(0.0, 3.0, 4.0)).with_time(10.0),
+        ..*target.first_event()
+    });
+    assert!(sender.send_light_signal(1.0, &late_target, c).is_none());
+}
+
+#[test]
+fn test_radar_ranging_moving_target() {
+    let c = 1.0;
+    let v = 0.6;
+
+    let observer = world_line(DVec3::ZERO, Rapidity::ZERO, DVec3::ZERO);
+
+    // target passes near the observer making a few turns
+    let mut target = world_line(
+        DVec3::new(-6.0, 0.0, 0.0),
+        Rapidity::from_velocity(DVec3::new(v, 0.0, 0.0), c),
+        DVec3::ZERO,
+    );
+    let mut event = *target.first_event();
+    event.coord = SpacetimeEvent::new(DVec3::new(-6.0, 0.0, 0.0)).with_time(-10.0);
+    target = WorldLine::new(event);
+    for _ in 0..4 {
+        event = event.advance(3.0, c);
+        let mut velocity = event.velocity(c);
+        velocity.y = -velocity.y + 0.1;
+        event.rapidity = Rapidity::from_velocity(velocity, c);
+        target.push(event, c).expect("valid event");
+    }
+
+    for i in 0..10 {
+        let emission_time = i as f64;
+
+        let echo = observer
+            .radar_ranging(emission_time, &target, c)
+            .expect("echo is received");
+
+        let reflection = echo.reflection.coord;
+        let emission = echo.emission.coord;
+        let reception = echo.reception.coord;
+
+        // reflection is on the future light cone of the emission and the past light cone of the
+        // reception
+        assert!(
+            ((reflection.pos - emission.pos).length() - c * (reflection.time - emission.time))
+                .abs()
+                < 1e-9
+        );
+        assert!(
+            ((reception.pos - reflection.pos).length() - c * (reception.time - reflection.time))
+                .abs()
+                < 1e-9
+        );
+
+        // for the inertial observer radar coordinates match its rest frame coordinates
+        assert!((echo.time() - reflection.time).abs() < 1e-9);
+        assert!((echo.distance(c) - reflection.pos.length()).abs() < 1e-9);
+
+        let expected = target.event_at_time(reflection.time, c).expect("event");
+        assert!((expected.coord.pos - reflection.pos).length() < 1e-9);
+        assert!((expected.object_proper_time - echo.reflection.object_proper_time).abs() < 1e-9);
+    }
+}
+
+#[test]
+fn test_light_signal_to_accelerated_target() {
+    let c = 1.0;
+    let acceleration = 1.0;
+
+    let sender = world_line(DVec3::ZERO, Rapidity::ZERO, DVec3::ZERO);
+    let target = world_line(
+        DVec3::new(0.5, 0.0, 0.0),
+        Rapidity::ZERO,
+        DVec3::new(acceleration, 0.0, 0.0),
+    );
+
+    // asymptote of the target's world line is `x = t - 0.5`, so signals emitted after `t = 0.5`
+    // never reach it
+    let signal = sender
+        .send_light_signal(0.2, &target, c)
+        .expect("signal is received");
+    let reception = signal.reception;
+
+    let s = acceleration * reception.object_proper_time / c;
+    assert!((reception.coord.pos.x - (0.5 + c * c / acceleration * (s.cosh() - 1.0))).abs() < 1e-9);
+    assert!((reception.coord.time - c / acceleration * s.sinh()).abs() < 1e-9);
+    assert!((reception.coord.pos.x - c * (reception.coord.time - 0.2)).abs() < 1e-9);
+
+    assert!(sender.send_light_signal(0.6, &target, c).is_none());
+
+    // the target can still send signals back to the sender
+    assert!(target.send_light_signal(10.0, &sender, c).is_some());
+}
+
+#[test]
+fn test_light_signal_to_ended_target() {
+    let c = 1.0;
+
+    let sender = world_line(DVec3::ZERO, Rapidity::ZERO, DVec3::ZERO);
+    let mut target = world_line(DVec3::new(2.0, 0.0, 0.0), Rapidity::ZERO, DVec3::ZERO);
+    target.end_at(3.0, c).expect("valid end");
+
+    assert!(sender.send_light_signal(0.5, &target, c).is_some());
+    assert!(sender.send_light_signal(1.5, &target, c).is_none());
+}
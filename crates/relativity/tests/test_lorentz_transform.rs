@@ -0,0 +1,96 @@
+use glam::{DQuat, DVec3};
+use relativity::*;
+
+const EPSILON: f64 = 1e-10;
+
+fn assert_transform_eq(a: LorentzTransform, b: LorentzTransform) {
+    assert!(
+        a.matrix.abs_diff_eq(b.matrix, 1e-9),
+        "{:?} != {:?}",
+        a.matrix,
+        b.matrix
+    );
+}
+
+#[test]
+fn test_lorentz_transform_boost_matches_event_transform() {
+    let c = 2.0;
+    let velocity = DVec3::new(0.3, -1.1, 0.6);
+    let event = SpacetimeEvent::new(DVec3::new(1.0, 2.0, 3.0)).with_time(-1.5);
+
+    let transform = LorentzTransform::from_boost(velocity, c);
+
+    let expected = event.to_reference_frame(velocity, c);
+    let transformed = transform.transform_event(event, c);
+
+    assert!((transformed.pos - expected.pos).length() < EPSILON);
+    assert!((transformed.time - expected.time).abs() < EPSILON);
+
+    let object_velocity = DVec3::new(-0.5, 0.2, 1.3);
+    let expected_velocity = velocity_to_new_rf(velocity, object_velocity, c);
+    assert!(
+        (transform.transform_velocity(object_velocity, c) - expected_velocity).length() < EPSILON
+    );
+}
+
+#[test]
+fn test_lorentz_transform_inverse() {
+    let c = 1.0;
+    let transform = LorentzTransform::from_boost_rotation(
+        DVec3::new(0.4, 0.2, -0.5),
+        DQuat::from_axis_angle(DVec3::new(1.0, 1.0, 0.0).normalize(), 0.7),
+        c,
+    );
+
+    assert_transform_eq(
+        transform.then(transform.inverse()),
+        LorentzTransform::IDENTITY,
+    );
+    assert_transform_eq(transform.inverse() * transform, LorentzTransform::IDENTITY);
+}
+
+#[test]
+fn test_lorentz_transform_decompose() {
+    let c = 3.0;
+    let velocity = DVec3::new(1.0, -0.5, 2.0);
+    let rotation = DQuat::from_euler(glam::EulerRot::XYZ, 0.3, -1.2, 0.5);
+
+    let transform = LorentzTransform::from_boost_rotation(velocity, rotation, c);
+    let (decomposed_velocity, decomposed_rotation) = transform.decompose(c);
+
+    assert!((decomposed_velocity - velocity).length() < EPSILON);
+    assert!(
+        decomposed_rotation.abs_diff_eq(rotation, EPSILON)
+            || decomposed_rotation.abs_diff_eq(-rotation, EPSILON)
+    );
+}
+
+#[test]
+fn test_wigner_rotation_of_perpendicular_boosts() {
+    let c = 1.0;
+    let v1 = DVec3::new(0.6, 0.0, 0.0);
+    let v2 = DVec3::new(0.0, 0.8, 0.0);
+
+    // collinear boosts produce no rotation
+    let collinear = wigner_rotation(v1, v1 * 0.5, c);
+    assert!(collinear.angle_between(DQuat::IDENTITY) < EPSILON);
+
+    let rotation = wigner_rotation(v1, v2, c);
+    let (axis, angle) = rotation.to_axis_angle();
+
+    let gamma_1 = lorentz_factor_from_vel(v1, c);
+    let gamma_2 = lorentz_factor_from_vel(v2, c);
+    // Wigner angle for perpendicular boosts
+    let expected_angle = ((gamma_1 + gamma_2) / (1.0 + gamma_1 * gamma_2)).acos();
+
+    assert!((axis.z.abs() - 1.0).abs() < EPSILON);
+    assert!(
+        (angle - expected_angle).abs() < 1e-8,
+        "{angle} != {expected_angle}"
+    );
+
+    // composition is a boost with the composed velocity plus the rotation
+    let composed = LorentzTransform::from_boost(v1, c).then(LorentzTransform::from_boost(v2, c));
+    let expected = LorentzTransform::from_boost_rotation(composed.boost_velocity(c), rotation, c);
+    assert_transform_eq(composed, expected);
+}
@@ -0,0 +1,66 @@
+use glam::DVec3;
+use relativity::*;
+
+const EPSILON: f64 = 1e-9;
+
+#[test]
+fn test_motion_state_matches_world_line_segment() {
+    let c = 2.0;
+    let proper_acceleration = DVec3::new(0.3, -0.5, 0.2);
+
+    let state = MotionState {
+        coord: SpacetimeEvent::new(DVec3::new(1.0, 2.0, -3.0)).with_time(4.0),
+        rapidity: Rapidity::from_velocity(DVec3::new(0.6, 0.9, -0.4), c),
+        proper_time: 1.5,
+    };
+
+    let advanced = state.advance(proper_acceleration, 2.5, c);
+    let expected = state
+        .to_world_line_event(proper_acceleration)
+        .advance(2.5, c);
+
+    assert!((advanced.coord.pos - expected.coord.pos).length() < EPSILON);
+    assert!((advanced.coord.time - expected.coord.time).abs() < EPSILON);
+    assert!((advanced.velocity(c) - expected.velocity(c)).length() < EPSILON);
+    assert!((advanced.proper_time - expected.object_proper_time).abs() < EPSILON);
+}
+
+#[test]
+fn test_motion_state_collinear_steps() {
+    let c = 1.0;
+    let proper_acceleration = DVec3::new(0.0, -2.0, 0.0);
+
+    let mut state = MotionState::new(SpacetimeEvent::ZERO);
+    state.rapidity = Rapidity::from_velocity(DVec3::new(0.0, 0.5, 0.0), c);
+
+    let single = state.advance(proper_acceleration, 3.0, c);
+
+    let mut stepped = state;
+    for _ in 0..3000 {
+        stepped = stepped.advance(proper_acceleration, 0.001, c);
+    }
+
+    assert!((stepped.proper_time - single.proper_time).abs() < EPSILON);
+    assert!((stepped.coord.time - single.coord.time).abs() < 1e-8);
+    assert!((stepped.coord.pos - single.coord.pos).length() < 1e-8);
+    assert!((stepped.velocity(c) - single.velocity(c)).length() < 1e-8);
+}
+
+#[test]
+fn test_motion_state_hyperbolic_motion_from_rest() {
+    let c = 1.0;
+    let acceleration = 0.5;
+
+    let mut state = MotionState::new(SpacetimeEvent::ZERO);
+    for _ in 0..1000 {
+        state = state.advance(DVec3::X * acceleration, 0.1, c);
+    }
+
+    let s = acceleration * state.proper_time / c;
+    let radius = c * c / acceleration;
+
+    assert!((state.rapidity.magnitude - s).abs() < EPSILON);
+    assert!((state.coord.time - radius / c * s.sinh()).abs() / state.coord.time < EPSILON);
+    assert!((state.coord.pos.x - radius * (s.cosh() - 1.0)).abs() / state.coord.pos.x < EPSILON);
+    assert!(state.velocity(c).length() <= c);
+}
@@ -0,0 +1,78 @@
+use glam::DVec3;
+use relativity::*;
+
+const EPSILON: f64 = 1e-10;
+
+#[test]
+fn test_doppler_factor_longitudinal_and_transverse() {
+    let c = 2.0;
+    let beta = 0.6;
+
+    // source moving away from the observer
+    let receding = doppler_factor(DVec3::new(beta * c, 0.0, 0.0), -DVec3::X, c);
+    assert!((receding - ((1.0 - beta) / (1.0 + beta)).sqrt()).abs() < EPSILON);
+
+    let approaching = doppler_factor(DVec3::new(-beta * c, 0.0, 0.0), -DVec3::X, c);
+    assert!((approaching - ((1.0 + beta) / (1.0 - beta)).sqrt()).abs() < EPSILON);
+
+    let transverse = doppler_factor(DVec3::new(0.0, beta * c, 0.0), -DVec3::X, c);
+    assert!((transverse - (1.0 - beta * beta).sqrt()).abs() < EPSILON);
+}
+
+#[test]
+fn test_aberrate_direction() {
+    let c = 1.0;
+    let beta = 0.8;
+
+    let direction = aberrate_direction(DVec3::Y, DVec3::new(beta * c, 0.0, 0.0), c);
+
+    assert!((direction.length() - 1.0).abs() < EPSILON);
+    assert!((direction.x - beta).abs() < EPSILON);
+
+    let back = aberrate_direction(direction, DVec3::new(-beta * c, 0.0, 0.0), c);
+    assert!((back - DVec3::Y).length() < EPSILON);
+}
+
+#[test]
+fn test_visible_event_doppler_factor_matches_proper_time_rate() {
+    let c = 1.0;
+
+    let world_line = WorldLine::new(WorldLineEvent {
+        rapidity: Rapidity::from_velocity(DVec3::new(-0.3, 0.4, 0.0), c),
+        coord: SpacetimeEvent::new(DVec3::new(10.0, -3.0, 2.0)),
+        object_proper_time: 0.0,
+        proper_acceleration: DVec3::ZERO,
+    });
+
+    let observer_velocity = DVec3::new(0.2, 0.0, -0.1);
+    let observer_coord = SpacetimeEvent::new(DVec3::ZERO).with_time(20.0);
+
+    let visible_event = world_line
+        .get_visible_event(ReferenceFrame::new(observer_coord, c).with_velocity(observer_velocity))
+        .expect("visible event");
+
+    // central difference over the observer's proper time
+    let dt = 1e-4;
+    let gamma = lorentz_factor_from_vel(observer_velocity, c);
+    let visible_proper_time = |proper_time_offset: f64| {
+        let coord = SpacetimeEvent {
+            pos: observer_coord.pos + observer_velocity * gamma * proper_time_offset,
+            time: observer_coord.time + gamma * proper_time_offset,
+        };
+
+        world_line
+            .get_visible_event(ReferenceFrame::new(coord, c).with_velocity(observer_velocity))
+            .expect("visible event")
+            .proper_time
+    };
+
+    let proper_time_rate = (visible_proper_time(dt) - visible_proper_time(-dt)) / (2.0 * dt);
+    let doppler = visible_event.doppler_factor(c);
+
+    assert!((proper_time_rate - doppler).abs() < 1e-6);
+    assert!((visible_event.intensity_factor(c) - doppler.powi(4)).abs() < EPSILON);
+    assert!((visible_event.angular_size_factor(c) * doppler - 1.0).abs() < EPSILON);
+
+    let rest_direction = visible_event.rest_frame_direction(c);
+    assert!((rest_direction.length() - 1.0).abs() < EPSILON);
+}
@@ -0,0 +1,79 @@
+use glam::DVec3;
+use relativity::{lorentz_factor_from_vel, velocity_to_new_rf, Rapidity, SpacetimeEvent};
+
+const EPSILON: f64 = 1e-10;
+
+#[test]
+fn test_rapidity_velocity_round_trip() {
+    let c = 3.0;
+    let velocity = DVec3::new(1.0, -2.0, 0.5);
+
+    let rapidity = Rapidity::from_velocity(velocity, c);
+
+    assert!((rapidity.to_velocity(c) - velocity).length() < EPSILON);
+    assert!((rapidity.beta() - velocity / c).length() < EPSILON);
+    assert!((rapidity.gamma() - lorentz_factor_from_vel(velocity, c)).abs() < EPSILON);
+    assert!(
+        (rapidity.proper_velocity(c) - velocity * lorentz_factor_from_vel(velocity, c)).length()
+            < EPSILON
+    );
+
+    let from_gamma = Rapidity::from_gamma(velocity, rapidity.gamma());
+    assert!((from_gamma.magnitude - rapidity.magnitude).abs() < EPSILON);
+}
+
+#[test]
+fn test_rapidity_compose_matches_velocity_transform() {
+    let c = 2.0;
+    let frame_velocity = DVec3::new(0.8, 0.3, -0.2);
+    let object_velocity = DVec3::new(-0.5, 1.1, 0.4);
+
+    let expected = velocity_to_new_rf(-frame_velocity, object_velocity, c);
+
+    let composed = Rapidity::from_velocity(frame_velocity, c)
+        .compose(Rapidity::from_velocity(object_velocity, c));
+
+    assert!((composed.to_velocity(c) - expected).length() < EPSILON);
+}
+
+#[test]
+fn test_rapidity_collinear_compose_is_additive() {
+    let direction = DVec3::new(1.0, 1.0, 0.0);
+
+    let composed = Rapidity::new(direction, 0.7).compose(Rapidity::new(direction, 1.2));
+    assert!((composed.magnitude - 1.9).abs() < EPSILON);
+
+    let opposite = Rapidity::new(direction, 0.7).compose(-Rapidity::new(direction, 1.2));
+    assert!((opposite.magnitude - 0.5).abs() < EPSILON);
+    assert!((opposite.direction + direction.normalize()).length() < EPSILON);
+}
+
+#[test]
+fn test_rapidity_long_acceleration_does_not_saturate() {
+    let step = Rapidity::new(DVec3::X, 0.01);
+
+    let mut rapidity = Rapidity::ZERO;
+    for _ in 0..360_000 {
+        rapidity = rapidity.compose(step);
+    }
+
+    assert!((rapidity.magnitude - 3600.0).abs() < 1e-6);
+    assert!((rapidity.direction - DVec3::X).length() < EPSILON);
+
+    let turned = rapidity.compose(Rapidity::new(DVec3::Y, 1.0));
+    assert!(turned.magnitude.is_finite());
+    assert!(turned.magnitude > rapidity.magnitude);
+}
+
+#[test]
+fn test_rapidity_event_from_rest_frame_matches_event_transform() {
+    let c = 2.0;
+    let velocity = DVec3::new(0.8, 0.3, -0.2);
+    let event = SpacetimeEvent::new(DVec3::new(1.0, -2.0, 0.5)).with_time(3.0);
+
+    let expected = event.to_reference_frame(-velocity, c);
+    let transformed = Rapidity::from_velocity(velocity, c).event_from_rest_frame(event, c);
+
+    assert!((transformed.pos - expected.pos).length() < EPSILON);
+    assert!((transformed.time - expected.time).abs() < EPSILON);
+}
@@ -0,0 +1,208 @@
+use glam::{DQuat, DVec3};
+use relativity::*;
+
+const EPSILON: f64 = 1e-9;
+
+fn assert_event_eq(a: SpacetimeEvent, b: SpacetimeEvent) {
+    assert!(
+        (a.pos - b.pos).length() < EPSILON && (a.time - b.time).abs() < EPSILON,
+        "{a:?} != {b:?}"
+    );
+}
+
+fn assert_vec_eq(a: DVec3, b: DVec3) {
+    assert!((a - b).length() < EPSILON, "{a:?} != {b:?}");
+}
+
+fn frame() -> ReferenceFrame {
+    ReferenceFrame::new(
+        SpacetimeEvent::new(DVec3::new(1.0, -2.0, 0.5)).with_time(3.0),
+        2.0,
+    )
+    .with_velocity(DVec3::new(0.4, 1.1, -0.3))
+    .with_orientation(DQuat::from_euler(glam::EulerRot::XYZ, 0.3, -1.2, 0.5))
+}
+
+#[test]
+fn test_reference_frame_matches_shift_and_boost() {
+    let c = 2.0;
+    let origin = SpacetimeEvent::new(DVec3::new(1.0, 2.0, 3.0)).with_time(-1.5);
+    let velocity = DVec3::new(0.3, -1.1, 0.6);
+    let frame = ReferenceFrame::new(origin, c).with_velocity(velocity);
+
+    let event = SpacetimeEvent::new(DVec3::new(-4.0, 0.5, 2.0)).with_time(7.0);
+    assert_event_eq(
+        frame.event_to_frame(event),
+        (event - origin).to_reference_frame(velocity, c),
+    );
+    assert_event_eq(frame.event_to_frame(origin), SpacetimeEvent::ZERO);
+
+    let object_velocity = DVec3::new(-0.5, 0.2, 1.3);
+    assert_vec_eq(
+        frame.velocity_to_frame(object_velocity),
+        velocity_to_new_rf(velocity, object_velocity, c),
+    );
+    assert_vec_eq(frame.velocity_to_frame(velocity), DVec3::ZERO);
+}
+
+#[test]
+fn test_reference_frame_round_trip() {
+    let frame = frame();
+
+    let event = SpacetimeEvent::new(DVec3::new(-4.0, 0.5, 2.0)).with_time(7.0);
+    assert_event_eq(frame.event_from_frame(frame.event_to_frame(event)), event);
+
+    let velocity = DVec3::new(-0.5, 0.2, 1.3);
+    assert_vec_eq(
+        frame.velocity_from_frame(frame.velocity_to_frame(velocity)),
+        velocity,
+    );
+
+    let acceleration = DVec3::new(0.7, -0.1, 2.0);
+    let frame_velocity = frame.velocity_to_frame(velocity);
+    let frame_acceleration = frame.acceleration_to_frame(velocity, acceleration);
+    assert_vec_eq(
+        frame.acceleration_from_frame(frame_velocity, frame_acceleration),
+        acceleration,
+    );
+}
+
+#[test]
+fn test_reference_frame_acceleration_matches_finite_difference() {
+    let frame = frame();
+    let c = frame.c;
+
+    let start = WorldLineEvent {
+        coord: SpacetimeEvent::new(DVec3::new(3.0, 1.0, -1.0)),
+        rapidity: Rapidity::from_velocity(DVec3::new(0.2, -0.9, 0.5), c),
+        object_proper_time: 0.0,
+        proper_acceleration: DVec3::new(-0.3, 0.5, 0.8),
+    };
+
+    let dt = 1e-4;
+    let frame_state = |proper_time: f64| {
+        let event = start.advance(proper_time, c);
+
+        (
+            frame.event_to_frame(event.coord).time,
+            frame.velocity_to_frame(event.velocity(c)),
+        )
+    };
+
+    let (before_time, before_velocity) = frame_state(-dt);
+    let (after_time, after_velocity) = frame_state(dt);
+    let expected = (after_velocity - before_velocity) / (after_time - before_time);
+
+    let (main_before, main_after) = (start.advance(-dt, c), start.advance(dt, c));
+    let acceleration = (main_after.velocity(c) - main_before.velocity(c))
+        / (main_after.coord.time - main_before.coord.time);
+
+    let transformed = frame.acceleration_to_frame(start.velocity(c), acceleration);
+    assert!(
+        (transformed - expected).length() < 1e-6,
+        "{transformed:?} != {expected:?}"
+    );
+}
+
+#[test]
+fn test_reference_frame_compose_and_inverse() {
+    let outer = frame();
+    let inner = ReferenceFrame::new(
+        SpacetimeEvent::new(DVec3::new(0.5, 0.0, -2.0)).with_time(1.0),
+        2.0,
+    )
+    .with_velocity(DVec3::new(-1.2, 0.3, 0.4))
+    .with_orientation(DQuat::from_axis_angle(DVec3::Y, 0.9));
+
+    let composed = outer.compose(&inner);
+
+    let event = SpacetimeEvent::new(DVec3::new(-4.0, 0.5, 2.0)).with_time(7.0);
+    assert_event_eq(
+        composed.event_to_frame(event),
+        inner.event_to_frame(outer.event_to_frame(event)),
+    );
+
+    let velocity = DVec3::new(-0.5, 0.2, 1.3);
+    assert_vec_eq(
+        composed.velocity_to_frame(velocity),
+        inner.velocity_to_frame(outer.velocity_to_frame(velocity)),
+    );
+
+    assert_event_eq(outer.compose(&outer.inverse()).event_to_frame(event), event);
+    assert_event_eq(
+        composed.relative_to(&outer).event_to_frame(event),
+        inner.event_to_frame(event),
+    );
+}
+
+#[test]
+fn test_reference_frame_rotates_world_line_queries() {
+    let c = 1.0;
+    let world_line = WorldLine::new(WorldLineEvent {
+        coord: SpacetimeEvent::new(DVec3::new(3.0, 0.0, 0.0)),
+        rapidity: Rapidity::from_velocity(DVec3::new(0.0, 0.4, 0.0), c),
+        object_proper_time: 0.0,
+        proper_acceleration: DVec3::ZERO,
+    });
+
+    let frame = ReferenceFrame::new(SpacetimeEvent::ZERO.with_time(10.0), c)
+        .with_velocity(DVec3::new(0.0, 0.0, 0.5));
+    let rotation = DQuat::from_axis_angle(DVec3::Z, 1.0);
+    let rotated = frame.with_orientation(rotation);
+
+    let visible = world_line.get_visible_event(frame).expect("visible event");
+    let rotated_visible = world_line
+        .get_visible_event(rotated)
+        .expect("visible event");
+
+    assert_vec_eq(
+        rotation * rotated_visible.relative_coord.pos,
+        visible.relative_coord.pos,
+    );
+    assert_vec_eq(
+        rotation * rotated_visible.relative_velocity,
+        visible.relative_velocity,
+    );
+    assert!((rotated_visible.proper_time - visible.proper_time).abs() < EPSILON);
+
+    let current = world_line.get_current_event(frame).expect("current event");
+    let rotated_current = world_line
+        .get_current_event(rotated)
+        .expect("current event");
+
+    assert_vec_eq(
+        rotation * rotated_current.relative_position,
+        current.relative_position,
+    );
+    assert_event_eq(rotated_current.coord, current.coord);
+}
+
+#[test]
+fn test_reference_frame_with_rounded_velocity() {
+    let c = 1.0;
+    let object_velocity = DVec3::new(0.999_999_999_999, 0.0, 0.0);
+    let world_line = WorldLine::new(WorldLineEvent {
+        coord: SpacetimeEvent::new(DVec3::new(10.0, 0.0, 0.0)).with_time(-1000.0),
+        rapidity: Rapidity::from_velocity(object_velocity, c),
+        object_proper_time: 0.0,
+        proper_acceleration: DVec3::ZERO,
+    });
+
+    // velocity of the frame rounds to the speed of light, the rapidity doesn't
+    let rapidity = Rapidity::new(DVec3::X, 20.0);
+    let frame = ReferenceFrame::main(c).with_rapidity(rapidity);
+    assert_eq!(frame.velocity(), DVec3::new(c, 0.0, 0.0));
+
+    let relative_rapidity = Rapidity::from_velocity(object_velocity, c).magnitude - 20.0;
+    let relative_velocity = DVec3::new(c * relative_rapidity.tanh(), 0.0, 0.0);
+
+    let visible = world_line.get_visible_event(frame).unwrap();
+    assert!((visible.relative_velocity - relative_velocity).length() < 1e-6);
+
+    let current = world_line.get_current_event(frame).expect("current event");
+    assert!((current.relative_velocity - relative_velocity).length() < 1e-6);
+
+    let (decomposed, _) = frame.lorentz_transform().decompose_rapidity(c);
+    assert!((decomposed.magnitude - rapidity.magnitude).abs() < 1e-6);
+    assert_vec_eq(decomposed.direction, rapidity.direction);
+}
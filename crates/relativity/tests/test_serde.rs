@@ -0,0 +1,146 @@
+#![cfg(feature = "serde")]
+
+use glam::{DQuat, DVec3};
+use relativity::*;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// Serialize, deserialize and serialize again, checking that both representations match.
+fn round_trip<T: Serialize + DeserializeOwned>(value: &T) -> T {
+    let json = serde_json::to_string(value).expect("serialize");
+    let deserialized: T = serde_json::from_str(&json).expect("deserialize");
+
+    assert_eq!(
+        serde_json::to_string(&deserialized).expect("serialize"),
+        json
+    );
+
+    deserialized
+}
+
+#[test]
+fn test_serde_world_line_round_trip() {
+    let c = 1.0;
+
+    let start = WorldLineEvent {
+        coord: SpacetimeEvent::new(DVec3::new(1.0, 2.0, 3.0)).with_time(-4.0),
+        rapidity: Rapidity::from_velocity(DVec3::new(0.1, -0.2, 0.3), c),
+        object_proper_time: 0.5,
+        proper_acceleration: DVec3::new(0.0, 0.1, 0.0),
+    };
+
+    let mut world_line = WorldLine::new(start);
+    world_line
+        .push(start.advance(2.0, c), c)
+        .expect("valid event");
+
+    let deserialized = round_trip(&world_line);
+    assert_eq!(deserialized.events().len(), 2);
+    assert_eq!(
+        deserialized.last_event().coord,
+        world_line.last_event().coord
+    );
+
+    let observer = SpacetimeEvent::new(DVec3::ZERO).with_time(10.0);
+    round_trip(
+        &world_line
+            .get_visible_event(ReferenceFrame::new(observer, c))
+            .expect("visible event"),
+    );
+    round_trip(
+        &world_line
+            .get_current_event(ReferenceFrame::new(observer, c))
+            .expect("current event"),
+    );
+
+    // world line can't be empty
+    assert!(serde_json::from_str::<WorldLine>(r#"{"events":[]}"#).is_err());
+}
+
+#[test]
+fn test_serde_world_line_is_validated() {
+    let event = |time: f64| {
+        serde_json::to_value(WorldLineEvent {
+            coord: SpacetimeEvent::ZERO.with_time(time),
+            rapidity: Rapidity::ZERO,
+            object_proper_time: time,
+            proper_acceleration: DVec3::ZERO,
+        })
+        .unwrap()
+    };
+    let world_line = |events: &[f64], end: Option<f64>| {
+        let json = serde_json::json!({
+            "events": events.iter().map(|time| event(*time)).collect::<Vec<_>>(),
+            "end": end.map(event),
+        });
+
+        serde_json::from_value::<WorldLine>(json).map_err(|error| error.to_string())
+    };
+
+    let valid = world_line(&[0.0, 1.0], Some(1.0)).expect("valid world line");
+    assert_eq!(valid.events().len(), 2);
+    assert_eq!(valid.end_event().map(|end| end.coord.time), Some(1.0));
+
+    assert_eq!(
+        world_line(&[], None).unwrap_err(),
+        WorldLineError::Empty.to_string()
+    );
+    assert_eq!(
+        world_line(&[0.0, 2.0, 1.0], None).unwrap_err(),
+        WorldLineError::NotCausallyOrdered {
+            prev_time: 2.0,
+            time: 1.0
+        }
+        .to_string()
+    );
+    assert_eq!(
+        world_line(&[0.0, 2.0], Some(1.0)).unwrap_err(),
+        WorldLineError::NotCausallyOrdered {
+            prev_time: 2.0,
+            time: 1.0
+        }
+        .to_string()
+    );
+}
+
+#[test]
+fn test_serde_types_round_trip() {
+    let c = 2.0;
+    let event = SpacetimeEvent::new(DVec3::new(1.0, 0.0, -1.0)).with_time(3.0);
+
+    assert_eq!(round_trip(&event), event);
+    assert_eq!(
+        round_trip(&SpacetimeSeparation::Timelike),
+        SpacetimeSeparation::Timelike
+    );
+    assert_eq!(round_trip(&event.light_cone(c)).origin, event);
+
+    let rapidity = Rapidity::new(DVec3::Y, 1.5);
+    assert_eq!(round_trip(&rapidity), rapidity);
+
+    let four_velocity = FourVelocity::from_rapidity(rapidity, c);
+    assert_eq!(round_trip(&four_velocity), four_velocity);
+    assert_eq!(
+        round_trip(&FourPosition::from_event(event, c)),
+        FourPosition::from_event(event, c)
+    );
+    let momentum = FourMomentum::from_four_velocity(2.0, four_velocity);
+    assert_eq!(round_trip(&momentum), momentum);
+
+    let transform = LorentzTransform::from_boost_rotation(
+        DVec3::new(0.5, 0.0, 0.0),
+        DQuat::from_rotation_z(0.3),
+        c,
+    );
+    assert_eq!(round_trip(&transform), transform);
+
+    let error = WorldLineError::NotCausallyOrdered {
+        prev_time: 1.0,
+        time: 0.0,
+    };
+    assert_eq!(round_trip(&error), error);
+    assert_eq!(
+        round_trip(&WorldLineQueryError::NotYetVisible),
+        WorldLineQueryError::NotYetVisible
+    );
+}
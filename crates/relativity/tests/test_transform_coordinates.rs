@@ -1,4 +1,4 @@
-use bevy::math::DVec3;
+use glam::DVec3;
 use relativity::*;
 
 const EPSILON: f64 = 1e-10;
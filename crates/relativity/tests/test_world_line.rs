@@ -1,21 +1,30 @@
-use bevy::math::DVec3;
-use relativity::{SpacetimeEvent, WorldLine, WorldLineEvent};
+use glam::DVec3;
+use relativity::{
+    Rapidity,
+    ReferenceFrame,
+    SpacetimeEvent,
+    WorldLine,
+    WorldLineError,
+    WorldLineEvent,
+    WorldLineQueryError,
+};
 
 #[test]
 fn test_world_line() {
     let c = 1.0;
 
     let world_line = WorldLine::new(WorldLineEvent {
-        velocity: DVec3::new(0.5 * c, 0.0, 0.0),
+        rapidity: Rapidity::from_velocity(DVec3::new(0.5 * c, 0.0, 0.0), c),
         coord: SpacetimeEvent::new(DVec3::new(2.0, 0.0, 0.0)),
         object_proper_time: 0.0,
+        proper_acceleration: DVec3::ZERO,
     });
 
     let observer_velocity = DVec3::new(0.0, 0.0, 0.0);
     let observer_coord = SpacetimeEvent::new(DVec3::new(0.0, 0.0, 0.0)).with_time(4.0);
 
     let visible_event = world_line
-        .get_visible_event(observer_coord, observer_velocity, c)
+        .get_visible_event(ReferenceFrame::new(observer_coord, c).with_velocity(observer_velocity))
         .expect("visible event");
 
     let invariant = (visible_event.relative_coord.time * c).powi(2)
@@ -25,3 +34,738 @@ fn test_world_line() {
         "intersection event invariant should be zero, as it lies on the light cone",
     );
 }
+
+#[test]
+fn test_world_line_visible_event_high_rapidity() {
+    let c = 1.0;
+
+    let world_line = WorldLine::new(WorldLineEvent {
+        rapidity: Rapidity::ZERO,
+        coord: SpacetimeEvent::new(DVec3::new(0.0, 5.0, 0.0)).with_time(-10.0),
+        object_proper_time: 0.0,
+        proper_acceleration: DVec3::ZERO,
+    });
+
+    for magnitude in [12.0, 14.0, 16.0] {
+        // the observer passes the object at the origin and coasts for 10 s of its proper time,
+        // so the object approaches it at almost the speed of light
+        let rapidity = Rapidity::new(DVec3::X, magnitude);
+        let origin =
+            rapidity.event_from_rest_frame(SpacetimeEvent::new(DVec3::ZERO).with_time(10.0), c);
+
+        let visible_event = world_line
+            .get_visible_event(ReferenceFrame::new(origin, c).with_rapidity(rapidity))
+            .expect("visible event");
+
+        // rounding of the observer's event in main reference frame moves the image by about
+        // `gamma^2 * f64::EPSILON`
+        let tolerance = 1e-8 + 1e-15 * rapidity.gamma().powi(2);
+        let relative_coord = visible_event.relative_coord;
+
+        assert!(
+            (relative_coord.pos - DVec3::new(-3.75, 5.0, 0.0)).length() < tolerance,
+            "rapidity {magnitude}: {relative_coord:?}"
+        );
+        assert!(
+            (relative_coord.time + 6.25).abs() < tolerance,
+            "rapidity {magnitude}: {relative_coord:?}"
+        );
+        assert!((visible_event.proper_time - 10.0).abs() < 1e-4);
+    }
+}
+
+#[test]
+fn test_world_line_hyperbolic_visible_event() {
+    let c = 1.0;
+    let acceleration = 0.5;
+
+    let world_line = WorldLine::new(WorldLineEvent {
+        rapidity: Rapidity::ZERO,
+        coord: SpacetimeEvent::new(DVec3::ZERO),
+        object_proper_time: 0.0,
+        proper_acceleration: DVec3::new(acceleration, 0.0, 0.0),
+    });
+
+    let observer_coord = SpacetimeEvent::new(DVec3::new(-5.0, 1.0, 0.0)).with_time(10.0);
+
+    let visible_event = world_line
+        .get_visible_event(ReferenceFrame::new(observer_coord, c))
+        .expect("visible event");
+
+    let invariant = (visible_event.relative_coord.time * c).powi(2)
+        - visible_event.relative_coord.pos.length_squared();
+    assert!(
+        invariant.abs() < 1e-8,
+        "intersection should lie on the light cone"
+    );
+    assert!(visible_event.relative_coord.time < 0.0);
+
+    // exact hyperbolic motion parametrized by proper time
+    let s = acceleration * visible_event.proper_time / c;
+    let coord = visible_event.relative_coord + observer_coord;
+    let expected_x = c * c / acceleration * (s.cosh() - 1.0);
+    let expected_time = c / acceleration * s.sinh();
+
+    assert!((coord.pos.x - expected_x).abs() < 1e-8);
+    assert!(coord.pos.y.abs() < 1e-8);
+    assert!((coord.time - expected_time).abs() < 1e-8);
+    assert!((visible_event.velocity.x - c * s.tanh()).abs() < 1e-8);
+}
+
+#[test]
+fn test_world_line_hyperbolic_current_event() {
+    let c = 2.0;
+    let acceleration = 1.5;
+
+    let world_line = WorldLine::new(WorldLineEvent {
+        rapidity: Rapidity::ZERO,
+        coord: SpacetimeEvent::new(DVec3::ZERO),
+        object_proper_time: 0.0,
+        proper_acceleration: DVec3::new(0.0, acceleration, 0.0),
+    });
+
+    let time = 3.0;
+    let observer_coord = SpacetimeEvent::new(DVec3::new(4.0, 0.0, 0.0)).with_time(time);
+
+    let current_event = world_line
+        .get_current_event(ReferenceFrame::new(observer_coord, c))
+        .expect("current event");
+
+    let expected_proper_time = c / acceleration * (acceleration * time / c).asinh();
+    let expected_y =
+        c * c / acceleration * ((1.0 + (acceleration * time / c).powi(2)).sqrt() - 1.0);
+
+    assert!((current_event.proper_time - expected_proper_time).abs() < 1e-8);
+    assert!((current_event.relative_position.y - expected_y).abs() < 1e-8);
+    assert!((current_event.relative_position.x + 4.0).abs() < 1e-8);
+    assert!((current_event.coord.time - time).abs() < 1e-8);
+}
+
+#[test]
+fn test_world_line_current_event_branches_agree() {
+    let c = 1.0;
+
+    let world_line = |proper_acceleration| {
+        WorldLine::new(WorldLineEvent {
+            rapidity: Rapidity::ZERO,
+            coord: SpacetimeEvent::new(DVec3::ZERO).with_time(-10.0),
+            object_proper_time: 0.0,
+            proper_acceleration,
+        })
+    };
+
+    let frame = ReferenceFrame::new(SpacetimeEvent::new(DVec3::ZERO), c).with_velocity(DVec3::new(
+        0.6 * c,
+        0.0,
+        0.0,
+    ));
+
+    let inertial = world_line(DVec3::ZERO)
+        .get_current_event(frame)
+        .expect("inertial current event");
+    let hyperbolic = world_line(DVec3::new(0.0, 1e-9, 0.0))
+        .get_current_event(frame)
+        .expect("hyperbolic current event");
+
+    // the object stays at the observer's position, so it is simultaneous to the origin
+    assert!((inertial.proper_time - 10.0).abs() < 1e-8);
+    assert!((hyperbolic.proper_time - inertial.proper_time).abs() < 1e-6);
+    assert!((hyperbolic.relative_position - inertial.relative_position).length() < 1e-6);
+}
+
+#[test]
+fn test_world_line_hyperbolic_current_event_numerical_failure() {
+    let c = 1.0;
+
+    let world_line = WorldLine::new(WorldLineEvent {
+        rapidity: Rapidity::ZERO,
+        coord: SpacetimeEvent::new(DVec3::ZERO).with_time(-10.0),
+        object_proper_time: 0.0,
+        proper_acceleration: DVec3::new(1.0, 0.0, 0.0),
+    });
+
+    // the observer's velocity rounds to the speed of light along the acceleration, so its
+    // simultaneity hyperplane can't be intersected with the segment in `f64`
+    let frame = ReferenceFrame::new(SpacetimeEvent::new(DVec3::ZERO), c)
+        .with_rapidity(Rapidity::new(DVec3::X, 20.0));
+
+    assert!(matches!(
+        world_line.get_current_event(frame),
+        Err(WorldLineQueryError::NumericalFailure { .. })
+    ));
+}
+
+#[test]
+fn test_world_line_hyperbolic_moving_observer() {
+    let c = 1.0;
+
+    let start = WorldLineEvent {
+        rapidity: Rapidity::from_velocity(DVec3::new(0.3, 0.2, 0.0), c),
+        coord: SpacetimeEvent::new(DVec3::new(1.0, 2.0, 0.0)).with_time(-3.0),
+        object_proper_time: 1.0,
+        proper_acceleration: DVec3::new(-0.2, 0.4, 0.1),
+    };
+    let world_line = WorldLine::new(start);
+
+    let observer_velocity = DVec3::new(-0.4, 0.1, 0.2);
+    let observer_coord = SpacetimeEvent::new(DVec3::new(-2.0, 0.0, 1.0)).with_time(5.0);
+
+    let visible_event = world_line
+        .get_visible_event(ReferenceFrame::new(observer_coord, c).with_velocity(observer_velocity))
+        .expect("visible event");
+
+    let invariant = (visible_event.relative_coord.time * c).powi(2)
+        - visible_event.relative_coord.pos.length_squared();
+    assert!(
+        invariant.abs() < 1e-8,
+        "intersection should lie on the light cone"
+    );
+
+    let expected = start.advance(visible_event.proper_time - start.object_proper_time, c);
+    assert!((expected.velocity(c) - visible_event.velocity).length() < 1e-8);
+
+    let current_event = world_line
+        .get_current_event(ReferenceFrame::new(observer_coord, c).with_velocity(observer_velocity))
+        .expect("current event");
+
+    let relative_coord =
+        (current_event.coord - observer_coord).to_reference_frame(observer_velocity, c);
+    assert!(
+        relative_coord.time.abs() < 1e-8,
+        "event should be simultaneous"
+    );
+    assert!((relative_coord.pos - current_event.relative_position).length() < 1e-8);
+    assert!(current_event.proper_time > visible_event.proper_time);
+}
+
+#[test]
+fn test_world_line_lookup_matches_linear_scan() {
+    let c = 1.0;
+
+    let mut event = WorldLineEvent {
+        rapidity: Rapidity::from_velocity(DVec3::new(0.3, 0.0, 0.0), c),
+        coord: SpacetimeEvent::new(DVec3::new(5.0, 0.0, 0.0)).with_time(-100.0),
+        object_proper_time: 0.0,
+        proper_acceleration: DVec3::ZERO,
+    };
+    let mut world_line = WorldLine::new(event);
+
+    for i in 0..1000 {
+        event = event.advance(0.5, c);
+        event.rapidity = Rapidity::from_velocity(
+            DVec3::new(
+                0.6 * (i as f64 * 0.1).sin(),
+                0.3 * (i as f64 * 0.07).cos(),
+                0.0,
+            ),
+            c,
+        );
+        world_line.push(event, c).expect("valid event");
+    }
+
+    let observer_velocity = DVec3::new(-0.2, 0.4, 0.0);
+
+    for step in 0..200 {
+        let observer_coord =
+            SpacetimeEvent::new(DVec3::new(0.0, 1.0, 0.0)).with_time(-120.0 + step as f64 * 4.0);
+
+        let expected_last = world_line.events().iter().rev().find(|event| {
+            (event.coord - observer_coord)
+                .to_reference_frame(observer_velocity, c)
+                .time
+                <= 0.0
+        });
+        let last = world_line.find_last_event(
+            ReferenceFrame::new(observer_coord, c).with_velocity(observer_velocity),
+        );
+        assert_eq!(
+            last.map(|event| event.object_proper_time),
+            expected_last.map(|event| event.object_proper_time)
+        );
+
+        let light_cone = observer_coord.light_cone(c);
+        let expected_visible = world_line
+            .events()
+            .iter()
+            .take_while(|event| light_cone.past_contains(event.coord))
+            .last();
+        let visible = world_line.find_last_visible_event(ReferenceFrame::new(observer_coord, c));
+        assert_eq!(
+            visible.map(|event| event.object_proper_time),
+            expected_visible.map(|event| event.object_proper_time)
+        );
+    }
+}
+
+#[test]
+fn test_world_line_push_validation() {
+    let c = 2.0;
+
+    let start = WorldLineEvent {
+        rapidity: Rapidity::from_velocity(DVec3::new(1.0, 0.0, 0.0), c),
+        coord: SpacetimeEvent::new(DVec3::ZERO),
+        object_proper_time: 3.0,
+        proper_acceleration: DVec3::ZERO,
+    };
+
+    assert_eq!(
+        WorldLine::try_new(
+            WorldLineEvent {
+                rapidity: Rapidity::from_velocity(DVec3::new(0.0, 2.0, 0.0), c),
+                ..start
+            },
+            c
+        )
+        .unwrap_err(),
+        WorldLineError::SuperluminalVelocity {
+            velocity: DVec3::new(0.0, 2.0, 0.0),
+            c
+        }
+    );
+
+    let mut world_line = WorldLine::try_new(start, c).expect("valid start");
+
+    let past_event = WorldLineEvent {
+        coord: SpacetimeEvent::new(DVec3::ZERO).with_time(-1.0),
+        ..start
+    };
+    assert_eq!(
+        world_line.push(past_event, c).unwrap_err(),
+        WorldLineError::NotCausallyOrdered {
+            prev_time: 0.0,
+            time: -1.0
+        }
+    );
+
+    let spacelike_event = WorldLineEvent {
+        coord: SpacetimeEvent::new(DVec3::new(5.0, 0.0, 0.0)).with_time(1.0),
+        ..start
+    };
+    assert_eq!(
+        world_line.push(spacelike_event, c).unwrap_err(),
+        WorldLineError::SpacelikeSegment {
+            interval_squared: -21.0
+        }
+    );
+
+    // timelike, but the last segment reaches x = 2 at this time
+    let off_world_line_event = WorldLineEvent {
+        coord: SpacetimeEvent::new(DVec3::new(1.5, 0.0, 0.0)).with_time(2.0),
+        ..start
+    };
+    match world_line.push(off_world_line_event, c).unwrap_err() {
+        WorldLineError::OffWorldLine { pos, expected_pos } => {
+            assert_eq!(pos, off_world_line_event.coord.pos);
+            assert!((expected_pos - DVec3::new(2.0, 0.0, 0.0)).length() < 1e-10);
+        }
+        error => panic!("unexpected error: {error}"),
+    }
+
+    let next_event = WorldLineEvent {
+        coord: SpacetimeEvent::new(DVec3::new(2.0, 0.0, 0.0)).with_time(2.0),
+        rapidity: Rapidity::from_velocity(DVec3::new(0.0, -1.5, 0.0), c),
+        object_proper_time: 100.0,
+        proper_acceleration: DVec3::new(0.0, 1.0, 0.0),
+    };
+    let pushed = world_line.push(next_event, c).expect("valid event");
+
+    let expected_proper_time = 3.0 + 2.0 * (1.0 - 0.25_f64).sqrt();
+    assert!((pushed.object_proper_time - expected_proper_time).abs() < 1e-10);
+
+    // proper time along the hyperbolic segment
+    let last_event = world_line.last_event().advance(1.5, c);
+    let pushed = world_line
+        .push(
+            WorldLineEvent {
+                proper_acceleration: DVec3::ZERO,
+                ..last_event
+            },
+            c,
+        )
+        .expect("valid event");
+    assert!((pushed.object_proper_time - (expected_proper_time + 1.5)).abs() < 1e-10);
+    let pushed_coord = pushed.coord;
+    assert_eq!(world_line.events().len(), 3);
+
+    assert_eq!(
+        world_line
+            .set_last_velocity(DVec3::new(3.0, 0.0, 0.0), c)
+            .unwrap_err(),
+        WorldLineError::SuperluminalVelocity {
+            velocity: DVec3::new(3.0, 0.0, 0.0),
+            c
+        }
+    );
+    let last_event = *world_line
+        .set_last_velocity(DVec3::new(0.0, 0.0, 1.0), c)
+        .expect("valid velocity");
+    assert!((last_event.velocity(c) - DVec3::new(0.0, 0.0, 1.0)).length() < 1e-12);
+    assert_eq!(last_event.coord, pushed_coord);
+    assert_eq!(world_line.events().len(), 3);
+
+    assert_eq!(
+        WorldLine::from_events([], c).unwrap_err(),
+        WorldLineError::Empty
+    );
+    let from_events =
+        WorldLine::from_events(world_line.events().iter().copied(), c).expect("valid events");
+    assert_eq!(from_events.events().len(), 3);
+}
+
+#[test]
+fn test_world_line_push_high_rapidity() {
+    let c = 1.0;
+
+    let mut event = WorldLineEvent {
+        rapidity: Rapidity::ZERO,
+        coord: SpacetimeEvent::ZERO,
+        object_proper_time: 0.0,
+        proper_acceleration: DVec3::X,
+    };
+    let mut world_line = WorldLine::new(event);
+
+    // thrust until the velocity rounds to the speed of light, coast and brake
+    for i in 0..60 {
+        event = event.advance(1.0, c);
+        event.proper_acceleration = match i {
+            0..29 => DVec3::X,
+            29..39 => DVec3::ZERO,
+            _ => -DVec3::X,
+        };
+
+        let pushed = world_line.push(event, c).expect("valid event");
+        assert!((pushed.object_proper_time - (i + 1) as f64).abs() < 1e-6);
+    }
+
+    assert_eq!(world_line.events()[30].velocity(c), DVec3::X * c);
+    assert!((world_line.events()[30].rapidity.magnitude - 30.0).abs() < 1e-9);
+    assert!((world_line.last_event().rapidity.magnitude - 10.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_world_line_query_errors() {
+    let c = 1.0;
+
+    let world_line = WorldLine::new(WorldLineEvent {
+        rapidity: Rapidity::from_velocity(DVec3::new(0.5 * c, 0.0, 0.0), c),
+        coord: SpacetimeEvent::new(DVec3::new(10.0, 0.0, 0.0)),
+        object_proper_time: 0.0,
+        proper_acceleration: DVec3::ZERO,
+    });
+
+    let observer_coord = SpacetimeEvent::new(DVec3::ZERO).with_time(5.0);
+
+    assert_eq!(
+        world_line
+            .get_visible_event(ReferenceFrame::new(observer_coord, c))
+            .unwrap_err(),
+        WorldLineQueryError::NotYetVisible
+    );
+    assert_eq!(
+        world_line
+            .get_current_event(ReferenceFrame::new(observer_coord.with_time(-1.0), c))
+            .unwrap_err(),
+        WorldLineQueryError::NotYetVisible
+    );
+
+    let observer_velocity = DVec3::new(0.0, c, 0.0);
+    assert_eq!(
+        world_line
+            .get_visible_event(
+                ReferenceFrame::new(observer_coord.with_time(20.0), c)
+                    .with_velocity(observer_velocity)
+            )
+            .unwrap_err(),
+        WorldLineQueryError::DegenerateVelocity {
+            velocity: observer_velocity,
+            c
+        }
+    );
+    assert!(world_line
+        .get_current_event(
+            ReferenceFrame::new(observer_coord, c).with_velocity(DVec3::new(f64::NAN, 0.0, 0.0))
+        )
+        .is_err());
+}
+
+#[test]
+fn test_world_line_end() {
+    let c = 1.0;
+
+    let start = WorldLineEvent {
+        rapidity: Rapidity::from_velocity(DVec3::new(0.5, 0.0, 0.0), c),
+        coord: SpacetimeEvent::new(DVec3::new(4.0, 0.0, 0.0)),
+        object_proper_time: 0.0,
+        proper_acceleration: DVec3::ZERO,
+    };
+    let mut world_line = WorldLine::new(start);
+
+    assert_eq!(
+        world_line.end_at(-1.0, c).unwrap_err(),
+        WorldLineError::NotCausallyOrdered {
+            prev_time: 0.0,
+            time: -1.0
+        }
+    );
+
+    let end = *world_line.end_at(2.0, c).expect("valid end");
+    assert_eq!(
+        end.coord,
+        SpacetimeEvent::new(DVec3::new(5.0, 0.0, 0.0)).with_time(2.0)
+    );
+    assert!((end.object_proper_time - 2.0 * (1.0 - 0.25_f64).sqrt()).abs() < 1e-10);
+    assert_eq!(
+        world_line.end_event().map(|event| event.coord),
+        Some(end.coord)
+    );
+
+    assert_eq!(
+        world_line.end_at(3.0, c).unwrap_err(),
+        WorldLineError::Ended { end_time: 2.0 }
+    );
+    assert_eq!(
+        world_line
+            .push(
+                WorldLineEvent {
+                    coord: SpacetimeEvent::new(DVec3::new(5.5, 0.0, 0.0)).with_time(3.0),
+                    ..start
+                },
+                c
+            )
+            .unwrap_err(),
+        WorldLineError::Ended { end_time: 2.0 }
+    );
+
+    let observer = SpacetimeEvent::new(DVec3::ZERO);
+    let already_ended = WorldLineQueryError::AlreadyEnded {
+        end_coord: end.coord,
+    };
+
+    // ended in the observer's reference frame, but the light from the end hasn't arrived yet
+    let observer_coord = observer.with_time(4.0);
+    assert_eq!(
+        world_line
+            .get_current_event(ReferenceFrame::new(observer_coord, c))
+            .unwrap_err(),
+        already_ended
+    );
+    let visible_event = world_line
+        .get_visible_event(ReferenceFrame::new(observer_coord, c))
+        .expect("visible event");
+    assert!(visible_event.relative_coord.time + 4.0 < 2.0);
+
+    // light from the end has arrived
+    let observer_coord = observer.with_time(7.0);
+    assert_eq!(
+        world_line
+            .get_visible_event(ReferenceFrame::new(observer_coord, c))
+            .unwrap_err(),
+        already_ended
+    );
+
+    // the end is simultaneous with different observer's events in different reference frames
+    let observer_coord = observer.with_time(2.5);
+    assert_eq!(
+        world_line
+            .get_current_event(ReferenceFrame::new(observer_coord, c))
+            .unwrap_err(),
+        already_ended
+    );
+    assert!(world_line
+        .get_current_event(
+            ReferenceFrame::new(observer_coord, c).with_velocity(DVec3::new(-0.4, 0.0, 0.0))
+        )
+        .is_ok());
+}
+
+#[test]
+fn test_world_line_single_light_cone_intersection() {
+    let c = 1.0;
+
+    let mut event = WorldLineEvent {
+        rapidity: Rapidity::from_velocity(DVec3::new(0.9, 0.0, 0.0), c),
+        coord: SpacetimeEvent::new(DVec3::new(3.0, 0.0, 0.0)).with_time(-30.0),
+        object_proper_time: 0.0,
+        proper_acceleration: DVec3::ZERO,
+    };
+    let mut world_line = WorldLine::new(event);
+
+    // zigzag with sharp turns and strongly accelerated segments
+    for i in 0..12 {
+        event = event.advance(1.5, c);
+        event.rapidity = Rapidity::from_velocity(
+            DVec3::new(if i % 2 == 0 { -0.95 } else { 0.9 }, 0.1, 0.0),
+            c,
+        );
+        event.proper_acceleration = DVec3::new(0.0, if i % 3 == 0 { 2.0 } else { 0.0 }, -0.5);
+        world_line.push(event, c).expect("valid event");
+    }
+
+    let events = world_line.events();
+    let proper_time_end = world_line.last_event().object_proper_time + 20.0;
+    let event_at = |proper_time: f64| {
+        let start = events
+            .iter()
+            .rev()
+            .find(|event| event.object_proper_time <= proper_time)
+            .unwrap();
+
+        start.advance(proper_time - start.object_proper_time, c)
+    };
+
+    for step in 0..40 {
+        let observer_coord =
+            SpacetimeEvent::new(DVec3::new(-2.0, 1.0, 0.0)).with_time(-20.0 + step as f64);
+
+        // count crossings of the past light cone sampling the world line by its proper time
+        let samples = 4000;
+        let mut crossings = Vec::new();
+        let mut prev_inside = observer_coord
+            .light_cone(c)
+            .past_contains(world_line.first_event().coord);
+        for i in 1..=samples {
+            let proper_time = proper_time_end * i as f64 / samples as f64;
+            let inside = observer_coord
+                .light_cone(c)
+                .past_contains(event_at(proper_time).coord);
+
+            if prev_inside && !inside {
+                crossings.push(proper_time);
+            }
+            assert!(
+                prev_inside || !inside,
+                "world line must not enter the past light cone again"
+            );
+            prev_inside = inside;
+        }
+
+        assert!(crossings.len() <= 1);
+
+        let visible_event = world_line.get_visible_event(ReferenceFrame::new(observer_coord, c));
+        match crossings.first() {
+            Some(crossing) => {
+                let visible_event = visible_event.expect("visible event");
+                assert!(
+                    (visible_event.proper_time - crossing).abs()
+                        < 2.0 * proper_time_end / samples as f64
+                );
+            }
+            None => assert_eq!(
+                visible_event.unwrap_err(),
+                WorldLineQueryError::NotYetVisible
+            ),
+        }
+    }
+}
+
+#[test]
+fn test_world_line_proper_time_indexing() {
+    let c = 2.0;
+
+    let start = WorldLineEvent {
+        rapidity: Rapidity::from_velocity(DVec3::new(1.0, 0.0, 0.0), c),
+        coord: SpacetimeEvent::new(DVec3::new(1.0, 0.0, 0.0)).with_time(-2.0),
+        object_proper_time: 5.0,
+        proper_acceleration: DVec3::ZERO,
+    };
+    let mut world_line = WorldLine::new(start);
+
+    let turn = WorldLineEvent {
+        rapidity: Rapidity::from_velocity(DVec3::new(-1.0, 0.5, 0.0), c),
+        proper_acceleration: DVec3::new(0.0, 0.0, 0.8),
+        ..start.advance(3.0, c)
+    };
+    world_line.push(turn, c).expect("valid event");
+
+    assert!(world_line.event_at_proper_time(4.0, c).is_none());
+    assert!(world_line.event_at_time(-3.0, c).is_none());
+    assert!(world_line.proper_time_at(-3.0, c).is_none());
+
+    // inside the inertial segment
+    let event = world_line.event_at_proper_time(6.0, c).expect("event");
+    let expected = start.advance(1.0, c);
+    assert!((event.coord.pos - expected.coord.pos).length() < 1e-10);
+    assert!((event.coord.time - expected.coord.time).abs() < 1e-10);
+    assert_eq!(event.rapidity, start.rapidity);
+
+    // inside the accelerated segment
+    let event = world_line.event_at_proper_time(10.0, c).expect("event");
+    let expected = turn.advance(2.0, c);
+    assert!((event.coord.pos - expected.coord.pos).length() < 1e-10);
+    assert!((event.velocity(c) - expected.velocity(c)).length() < 1e-10);
+
+    // coordinate time and proper time map back and forth
+    for i in 0..20 {
+        let time = -2.0 + i as f64 * 0.7;
+
+        let proper_time = world_line.proper_time_at(time, c).expect("proper time");
+        let event = world_line.event_at_time(time, c).expect("event");
+        assert!((event.coord.time - time).abs() < 1e-8);
+        assert!((event.object_proper_time - proper_time).abs() < 1e-12);
+
+        let event = world_line
+            .event_at_proper_time(proper_time, c)
+            .expect("event");
+        assert!((event.coord.time - time).abs() < 1e-8);
+    }
+
+    let end = *world_line.end_at(10.0, c).expect("valid end");
+    assert!(world_line.event_at_time(10.5, c).is_none());
+    assert!(world_line
+        .event_at_proper_time(end.object_proper_time + 0.1, c)
+        .is_none());
+    assert!(world_line
+        .event_at_proper_time(end.object_proper_time, c)
+        .is_some());
+}
+
+#[test]
+fn test_world_line_end_inside_earlier_segment() {
+    let c = 1.0;
+
+    let mut event = WorldLineEvent {
+        rapidity: Rapidity::from_velocity(DVec3::new(0.5, 0.0, 0.0), c),
+        coord: SpacetimeEvent::ZERO,
+        object_proper_time: 0.0,
+        proper_acceleration: DVec3::ZERO,
+    };
+    let mut world_line = WorldLine::new(event);
+
+    // planned maneuvers: accelerate, then coast, then turn around
+    for proper_acceleration in [
+        DVec3::new(0.0, 0.3, 0.0),
+        DVec3::ZERO,
+        DVec3::new(-1.0, 0.0, 0.0),
+    ] {
+        event = event.advance(2.0, c);
+        event.proper_acceleration = proper_acceleration;
+        world_line.push(event, c).expect("valid event");
+    }
+    assert_eq!(world_line.events().len(), 4);
+
+    // inside the accelerated second segment
+    let second_segment = world_line.events()[1];
+    let time = second_segment.coord.time + 1.0;
+    let expected = world_line.event_at_time(time, c).expect("event");
+
+    let end = *world_line.end_at(time, c).expect("valid end");
+    assert_eq!(world_line.events().len(), 2);
+    assert_eq!(
+        world_line.last_event().coord,
+        second_segment.coord,
+        "events up to the end are kept"
+    );
+    assert!((end.coord.pos - expected.coord.pos).length() < 1e-10);
+    assert!((end.coord.time - time).abs() < 1e-10);
+    assert!((end.velocity(c) - expected.velocity(c)).length() < 1e-10);
+    assert!((end.object_proper_time - expected.object_proper_time).abs() < 1e-10);
+
+    // the removed maneuvers never happen
+    assert!(world_line.event_at_time(time + 0.1, c).is_none());
+    assert_eq!(
+        world_line.end_at(time + 1.0, c).unwrap_err(),
+        WorldLineError::Ended {
+            end_time: end.coord.time
+        }
+    );
+}
@@ -1,23 +1,70 @@
 use bevy::prelude::*;
+use bevy_relativity::{sys_apply_scenario, RelativityPlugin, Scenario, SimulationArgs};
+use clap::error::ErrorKind;
+use clap::{CommandFactory, Parser};
 use stars::*;
 
+/// Relativistic stars: fly through a 3D cloud of stars and see them as light shows them.
+#[derive(Debug, Parser)]
+struct Cli {
+    #[command(flatten)]
+    simulation: SimulationArgs,
+
+    /// Width of the window in logical pixels
+    #[arg(long, default_value_t = 1280.0)]
+    width: f32,
+
+    /// Height of the window in logical pixels
+    #[arg(long, default_value_t = 720.0)]
+    height: f32,
+}
+
 fn main() {
-    App::new()
-        .add_plugins((DefaultPlugins.set(ImagePlugin::default_nearest()),))
-        .insert_resource(ClearColor(Color::BLACK))
-        .insert_resource(RelativeSettings {
-            speed_of_light: 10.0,
-        })
-        .add_systems(Startup, (sys_setup, sys_setup_ui))
-        .add_systems(
-            Update,
-            (
-                sys_control_observer,
-                sys_update_camera,
-                sys_update_observer,
-                sys_update_relative_objects,
-                sys_debug_text,
-            ),
-        )
-        .run();
+    let cli = Cli::parse();
+
+    let scenario = cli
+        .simulation
+        .load_scenario(DEFAULT_SCENARIO)
+        .unwrap_or_else(|error| Cli::command().error(ErrorKind::InvalidValue, error).exit());
+
+    let window = Window {
+        resolution: (cli.width, cli.height).into(),
+        ..default()
+    };
+
+    let mut app = App::new();
+
+    app.add_plugins((
+        DefaultPlugins
+            .set(WindowPlugin {
+                primary_window: Some(window),
+                ..default()
+            })
+            .set(ImagePlugin::default_nearest()),
+        RelativityPlugin {
+            speed_of_light: scenario.speed_of_light,
+        },
+    ))
+    .insert_resource(ClearColor(Color::BLACK))
+    .add_systems(Startup, (sys_setup, sys_setup_ui))
+    .add_systems(
+        PreUpdate,
+        sys_spawn_scenario_stars
+            .after(sys_apply_scenario)
+            .run_if(resource_exists_and_changed::<Scenario>),
+    )
+    .add_systems(
+        Update,
+        (
+            sys_read_control_input,
+            sys_update_camera,
+            (sys_update_star_materials, sys_debug_text),
+        ),
+    );
+
+    cli.simulation
+        .setup(&mut app, scenario)
+        .expect("failed to set up input files");
+
+    app.run();
 }
@@ -1,52 +1,48 @@
 use crate::*;
 use bevy::core_pipeline::bloom::BloomSettings;
 use bevy::core_pipeline::tonemapping::Tonemapping;
-use bevy::math::DVec3;
 use bevy::prelude::*;
-use relativity::SpacetimeEvent;
+use bevy_relativity::{
+    ControlledObserver,
+    DopplerPalette,
+    ObjectImage,
+    RelativeObject,
+    Scenario,
+    ScenarioObject,
+    ScenarioObserver,
+    VisibilityStats,
+};
 
-struct ObjectInit {
-    coord: SpacetimeEvent,
-    velocity: DVec3,
+/// Scenario used when no scenario file is given.
+pub const DEFAULT_SCENARIO: &str = include_str!("../scenarios/cube.ron");
+
+/// Everything needed to spawn stars whenever the scenario changes.
+#[derive(Debug, Resource)]
+pub struct StarSpawner {
+    mesh: Handle<Mesh>,
+    observer: Entity,
 }
 
 const OBJECT_SIZE: f32 = 1.0;
 
+/// Emissive color of the star at rest relative to the observer.
+pub const STAR_EMISSIVE: LinearRgba = LinearRgba::rgb(20.0, 20.0, 0.0);
+
 pub fn sys_setup(
     mut commands: Commands,
     mut meshes: ResMut<Assets<Mesh>>,
     mut materials: ResMut<Assets<StandardMaterial>>,
+    scenario: Res<Scenario>,
 ) {
     let sphere = meshes.add(Sphere {
         radius: OBJECT_SIZE * 0.5,
     });
 
-    let star_material = materials.add(StandardMaterial {
-        emissive: LinearRgba::rgb(20.0, 20.0, 0.0),
-        ..Default::default()
-    });
-
-    let next_id = 0;
-
-    let _next_id = grid_objects(
-        &mut commands,
-        SpacetimeEvent::new(DVec3::X * 4.0).with_time(-1000.0),
-        DVec3::ZERO,
-        32,
-        100.0,
-        next_id,
-        sphere.clone(),
-        star_material.clone(),
-    );
-
-    commands
+    let observer = commands
         .spawn(Name::new("observer"))
-        .insert(ObserverData {
-            proper_time: 0.0,
-            velocity: DVec3::ZERO,
-            coord: SpacetimeEvent::ZERO,
-            acceleration: 10.0,
-        })
+        // stars are seen by the first observer of the scenario only
+        .insert(scenario.observer(0).observer_data(scenario.speed_of_light))
+        .insert(ScenarioObserver(0))
         .insert(Camera3dBundle {
             camera: Camera {
                 hdr: true,
@@ -56,61 +52,58 @@ pub fn sys_setup(
             ..Default::default()
         })
         .insert(BloomSettings::NATURAL)
-        .insert(MainCamera);
-}
-
-/// Spawn objects in grid pattern
-fn grid_objects(
-    commands: &mut Commands,
-    coord: SpacetimeEvent,
-    velocity: DVec3,
-    size: u32,
-    spacing: f64,
-    id_start: u32,
-    mesh: Handle<Mesh>,
-    material: Handle<StandardMaterial>,
-) -> u32 {
-    let offset = (size + 1) as f64 * spacing * 0.5;
+        .insert(MainCamera)
+        .insert(ControlledObserver)
+        .insert(VisibilityStats::default())
+        .id();
 
-    for i in 0..size {
-        for j in 0..size {
-            for k in 0..size {
-                let id = id_start + i * size * size + j * size + k;
+    commands.insert_resource(StarSpawner {
+        mesh: sphere,
+        observer,
+    });
 
-                let mut object_coord = coord;
-                object_coord.pos += DVec3::new(
-                    k as f64 * spacing - offset,
-                    j as f64 * spacing - offset,
-                    i as f64 * spacing - offset,
-                );
+    commands.insert_resource(DopplerPalette::new(
+        STAR_EMISSIVE,
+        &mut materials,
+        |emissive| StandardMaterial {
+            emissive,
+            ..Default::default()
+        },
+    ));
+}
 
-                ObjectInit {
-                    coord: object_coord,
-                    velocity,
-                }
-                .spawn(commands, id, mesh.clone(), material.clone());
-            }
+/// Spawn stars of the [`Scenario`] whenever it changes, stars of the previous one are despawned
+/// by [`bevy_relativity::sys_apply_scenario`].
+pub fn sys_spawn_scenario_stars(
+    mut commands: Commands,
+    scenario: Res<Scenario>,
+    spawner: Res<StarSpawner>,
+    palette: Res<DopplerPalette<StandardMaterial>>,
+) {
+    let world_lines = match scenario.world_lines() {
+        Ok(world_lines) => world_lines,
+        Err(error) => {
+            warn!("invalid scenario: {error}");
+            return;
         }
-    }
+    };
 
-    id_start + size * size
-}
+    for (id, world_line) in (0..).zip(world_lines) {
+        let mut object = commands.spawn(PbrBundle {
+            mesh: spawner.mesh.clone(),
+            material: palette.unshifted().clone(),
+            ..default()
+        });
 
-impl ObjectInit {
-    fn spawn(
-        &self,
-        commands: &mut Commands,
-        id: u32,
-        mesh: Handle<Mesh>,
-        material: Handle<StandardMaterial>,
-    ) {
-        commands
-            .spawn(PbrBundle {
-                mesh,
-                material,
-                ..default()
-            })
+        // star is rendered as its own image for the only observer
+        let object_entity = object.id();
+        object
             .insert(Name::new(format!("object_{}", id)))
-            .insert(RelativeObject::new(id, self.coord, self.velocity));
+            .insert(RelativeObject::from_world_line(id, world_line))
+            .insert(ObjectImage {
+                object: object_entity,
+                observer: spawner.observer,
+            })
+            .insert(ScenarioObject);
     }
 }
@@ -1,16 +1,18 @@
 use crate::*;
 use bevy::color::palettes::css::{BLUE, GREEN, LIGHT_GRAY, RED};
 use bevy::prelude::*;
+use bevy_relativity::{ControlledObserver, ObserverData, RelativeSettings, VisibilityStats};
 
 pub fn sys_debug_text(
     mut text_query: Query<&mut Text, With<DebugText>>,
-    observer_query: Query<&ObserverData>,
+    observer_query: Query<(&ObserverData, &VisibilityStats), With<ControlledObserver>>,
     settings: Res<RelativeSettings>,
 ) {
     let mut text = text_query.single_mut();
-    let observer = observer_query.single();
+    let (observer, stats) = observer_query.single();
 
     let c = settings.speed_of_light;
+    let velocity = observer.velocity(c);
 
     *text = Text::from_sections([
         section("c", LIGHT_GRAY),
@@ -19,26 +21,52 @@ pub fn sys_debug_text(
         section("Velocity", LIGHT_GRAY),
         line_break(),
         section("\t|V|", LIGHT_GRAY),
+        section(format!(" = {:.6} * c", velocity.length() / c), Color::WHITE),
+        line_break(),
+        section("\tV.x", RED),
+        section(format!(" = {:.6} * c", velocity.x / c), Color::WHITE),
+        line_break(),
+        section("\tV.y", GREEN),
+        section(format!(" = {:.6} * c", velocity.y / c), Color::WHITE),
+        line_break(),
+        section("\tV.z", BLUE),
+        section(format!(" = {:.6} * c", velocity.z / c), Color::WHITE),
+        line_break(),
+        section("Proper acceleration", LIGHT_GRAY),
         section(
-            format!(" = {:.6} * c", observer.velocity.length() / c),
+            format!(" = {:.6}", observer.proper_acceleration.length()),
             Color::WHITE,
         ),
         line_break(),
-        section("\tV.x", RED),
+        section("Coordinate acceleration", LIGHT_GRAY),
         section(
-            format!(" = {:.6} * c", observer.velocity.x / c),
+            format!(" = {:.6}", observer.coordinate_acceleration(c).length()),
             Color::WHITE,
         ),
         line_break(),
-        section("\tV.y", GREEN),
+        section("Rapidity", LIGHT_GRAY),
         section(
-            format!(" = {:.6} * c", observer.velocity.y / c),
+            format!(" = {:.6}", observer.rapidity.magnitude),
             Color::WHITE,
         ),
         line_break(),
-        section("\tV.z", BLUE),
+        section("Gamma", LIGHT_GRAY),
+        section(format!(" = {:.6}", observer.rapidity.gamma()), Color::WHITE),
+        line_break(),
+        section("Visible", LIGHT_GRAY),
+        section(format!(" = {}", stats.visible), Color::WHITE),
+        line_break(),
+        section("Not yet visible", LIGHT_GRAY),
+        section(format!(" = {}", stats.not_yet_visible), Color::WHITE),
+        line_break(),
+        section("Failures", RED),
         section(
-            format!(" = {:.6} * c", observer.velocity.z / c),
+            format!(
+                " = {} (degenerate velocity: {}, numerical: {})",
+                stats.failures(),
+                stats.degenerate_velocity,
+                stats.numerical_failures
+            ),
             Color::WHITE,
         ),
     ]);
@@ -1,50 +1,12 @@
-use crate::*;
 use bevy::math::DVec3;
 use bevy::prelude::*;
-use relativity::{lorentz_factor_from_vel, velocity_to_new_rf, SpacetimeEvent};
+use bevy_relativity::PendingControlInput;
 
-/// observer data
-#[derive(Debug, Component)]
-pub struct ObserverData {
-    pub proper_time: f64,
-    /// Velocity of the observer in main reference frame.
-    pub velocity: DVec3,
-    /// Current position in main reference frame.
-    pub coord: SpacetimeEvent,
-
-    pub acceleration: f64,
-}
-
-pub fn sys_update_observer(
-    mut observer_query: Query<&mut ObserverData>,
-    time: Res<Time>,
-    settings: Res<RelativeSettings>,
-) {
-    let mut observer = observer_query.single_mut();
-
-    let proper_time_delta = time.delta_seconds_f64();
-
-    let c = settings.speed_of_light;
-    let gamma = lorentz_factor_from_vel(observer.velocity, c);
-
-    observer.proper_time += proper_time_delta;
-
-    let time_delta = proper_time_delta * gamma;
-    observer.coord = SpacetimeEvent {
-        pos: observer.coord.pos + observer.velocity * time_delta,
-        time: observer.coord.time + time_delta,
-    };
-}
-
-pub fn sys_control_observer(
+/// Read thrust direction from WASD, space and left shift.
+pub fn sys_read_control_input(
     keyboard_input: Res<ButtonInput<KeyCode>>,
-    mut observer_query: Query<&mut ObserverData>,
-    time: Res<Time>,
-    settings: Res<RelativeSettings>,
+    mut pending: ResMut<PendingControlInput>,
 ) {
-    let dt = time.delta_seconds_f64();
-    let c = settings.speed_of_light;
-
     let mut velocity_dir = DVec3::ZERO;
 
     if keyboard_input.pressed(KeyCode::Space) {
@@ -66,13 +28,5 @@ pub fn sys_control_observer(
         velocity_dir += DVec3::new(0.0, 0.0, 1.0);
     }
 
-    if velocity_dir == DVec3::ZERO {
-        return;
-    }
-
-    let mut observer = observer_query.single_mut();
-
-    let delta_velocity = velocity_dir.normalize() * dt * observer.acceleration;
-
-    observer.velocity = velocity_to_new_rf(-observer.velocity, delta_velocity, c);
+    pending.0.thrust = velocity_dir.normalize_or_zero();
 }